@@ -7,19 +7,20 @@ fn main() {
         value: 1500.0,
         deviation: 200.0,
     };
-    let mut results = vec![];
-    results.push(GameResult::win(GlickoRating {
-        value: 1400.0,
-        deviation: 30.0,
-    }));
-    results.push(GameResult::loss(GlickoRating {
-        value: 1550.0,
-        deviation: 100.0,
-    }));
-    results.push(GameResult::loss(GlickoRating {
-        value: 1700.0,
-        deviation: 300.0,
-    }));
+    let results = vec![
+        GameResult::win(GlickoRating {
+            value: 1400.0,
+            deviation: 30.0,
+        }),
+        GameResult::loss(GlickoRating {
+            value: 1550.0,
+            deviation: 100.0,
+        }),
+        GameResult::loss(GlickoRating {
+            value: 1700.0,
+            deviation: 300.0,
+        }),
+    ];
     // We are converting the result of new_rating to a GlickoRating immediately, throwing away the
     // benefits of Glicko2 over Glicko for the sake of matching the example in the glicko2 pdf.
     // In a real application, you'd likely want to save the Glicko2Rating and convert to
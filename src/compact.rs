@@ -0,0 +1,90 @@
+//! A compact fixed-point binary encoding of `Glicko2Rating`s, for storing large numbers of ratings.
+//!
+//! An encoded rating is 10 bytes, rather than the 24 bytes of a `Glicko2Rating<f64>`:
+//! the value and deviation are each stored as an `i32` in millionths, and the volatility as a `u16`
+//! in units of 1/50,000, all little-endian. This loses precision:
+//!
+//! - The value and deviation are rounded to the nearest 0.000001 on the Glicko2 scale,
+//!   which is less than 0.0002 on the Glicko scale, and are limited to ±2147.48.
+//! - The volatility is rounded to the nearest 0.00002, and is limited to the range [0, 1.31].
+//!
+//! Fields outside of these ranges are saturated to the nearest representable value,
+//! and NaN fields are encoded as zero. For ratings produced by [`new_rating`](../fn.new_rating.html)
+//! in any realistic setting, only the rounding applies.
+
+use num_traits::Float;
+use {cast, Glicko2Rating};
+
+/// The length in bytes of an encoded rating.
+pub const ENCODED_LEN: usize = 10;
+
+const VALUE_SCALE: f64 = 1_000_000.0;
+const VOLATILITY_SCALE: f64 = 50_000.0;
+
+/// Encodes `rating` into its compact representation.
+pub fn encode<F: Float>(rating: &Glicko2Rating<F>) -> [u8; ENCODED_LEN] {
+    let to_fixed = |field: F, scale: f64| (field.to_f64().unwrap() * scale).round();
+    let value = to_fixed(rating.value, VALUE_SCALE) as i32;
+    let deviation = to_fixed(rating.deviation, VALUE_SCALE) as i32;
+    let volatility = to_fixed(rating.volatility, VOLATILITY_SCALE) as u16;
+
+    let mut bytes = [0; ENCODED_LEN];
+    bytes[0..4].copy_from_slice(&value.to_le_bytes());
+    bytes[4..8].copy_from_slice(&deviation.to_le_bytes());
+    bytes[8..10].copy_from_slice(&volatility.to_le_bytes());
+    bytes
+}
+
+/// Decodes a rating from its compact representation, as produced by [`encode`](fn.encode.html).
+pub fn decode<F: Float>(bytes: &[u8; ENCODED_LEN]) -> Glicko2Rating<F> {
+    let value = i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    let deviation = i32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+    let volatility = u16::from_le_bytes([bytes[8], bytes[9]]);
+    Glicko2Rating {
+        value: cast(f64::from(value) / VALUE_SCALE),
+        deviation: cast(f64::from(deviation) / VALUE_SCALE),
+        volatility: cast(f64::from(volatility) / VOLATILITY_SCALE),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use GlickoRating;
+
+    #[test]
+    fn test_round_trip() {
+        let rating = Glicko2Rating::from(GlickoRating {
+            value: 1464.06,
+            deviation: 151.52,
+        });
+        let rating = Glicko2Rating {
+            volatility: 0.05999,
+            ..rating
+        };
+        let decoded: Glicko2Rating = decode(&encode(&rating));
+        assert!((decoded.value - rating.value).abs() <= 0.0000005);
+        assert!((decoded.deviation - rating.deviation).abs() <= 0.0000005);
+        assert!((decoded.volatility - rating.volatility).abs() <= 0.00001);
+
+        let negative = Glicko2Rating {
+            value: -3.25,
+            deviation: 0.5,
+            volatility: 0.06,
+        };
+        assert_eq!(decode::<f64>(&encode(&negative)), negative);
+    }
+
+    #[test]
+    fn test_saturation() {
+        let extreme = Glicko2Rating {
+            value: 1e10,
+            deviation: f64::NAN,
+            volatility: 5.0,
+        };
+        let decoded: Glicko2Rating = decode(&encode(&extreme));
+        assert_eq!(decoded.value, f64::from(i32::MAX) / 1_000_000.0);
+        assert_eq!(decoded.deviation, 0.0);
+        assert_eq!(decoded.volatility, f64::from(u16::MAX) / 50_000.0);
+    }
+}
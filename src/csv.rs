@@ -0,0 +1,269 @@
+//! Reading games from and writing ratings to CSV, using the [csv](https://docs.rs/csv) crate.
+//!
+//! Games are read from CSV with a header row naming the columns `player_rating`, `player_rd`,
+//! `opponent_rating`, `opponent_rd` and `score`, in any order. Ratings and deviations are on the
+//! Glicko scale. Ratings are written back out with the columns `player_rating` and `player_rd`.
+//!
+//! Every row is checked as it is read: ratings and deviations must be finite, deviations must not
+//! be negative, and scores must be in the range [0.0, 1.0].
+
+use core::fmt;
+use core::str::FromStr;
+use csv_crate::{Reader, StringRecord, Writer};
+use num_traits::Float;
+use std::error;
+use std::io::{Read, Write};
+use {GameResult, Glicko2Error, GlickoRating, Score};
+
+const PLAYER_RATING: &str = "player_rating";
+const PLAYER_RD: &str = "player_rd";
+const OPPONENT_RATING: &str = "opponent_rating";
+const OPPONENT_RD: &str = "opponent_rd";
+const SCORE: &str = "score";
+
+/// A game read from CSV: the rating of the player, paired with the result of the game.
+pub type Game<F = f64> = (GlickoRating<F>, GameResult<F>);
+
+/// An error that can occur while reading or writing CSV.
+#[derive(Debug)]
+pub enum CsvError {
+    /// The underlying CSV reader or writer failed, such as due to an I/O error or malformed CSV.
+    Csv(csv_crate::Error),
+    /// The header row did not contain the named column.
+    MissingColumn {
+        /// The name of the missing column.
+        column: &'static str,
+    },
+    /// The named column could not be parsed as a number.
+    InvalidNumber {
+        /// The line on which the invalid number was found, starting from 1.
+        line: u64,
+        /// The name of the column containing the invalid number.
+        column: &'static str,
+    },
+    /// The named column was a number, but not a valid rating, deviation or score,
+    /// such as a non-finite deviation or a score outside of the range [0.0, 1.0].
+    InvalidValue {
+        /// The line on which the invalid value was found, starting from 1.
+        line: u64,
+        /// The name of the column containing the invalid value.
+        column: &'static str,
+        /// Why the value is invalid.
+        error: Glicko2Error,
+    },
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CsvError::Csv(ref err) => write!(f, "{}", err),
+            CsvError::MissingColumn { column } => write!(f, "missing column `{}`", column),
+            CsvError::InvalidNumber { line, column } => {
+                write!(f, "column `{}` on line {} is not a valid number", column, line)
+            }
+            CsvError::InvalidValue { line, column, error } => {
+                write!(f, "column `{}` on line {} is invalid: {}", column, line, error)
+            }
+        }
+    }
+}
+
+impl error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            CsvError::Csv(ref err) => Some(err),
+            CsvError::InvalidValue { ref error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<csv_crate::Error> for CsvError {
+    fn from(err: csv_crate::Error) -> CsvError {
+        CsvError::Csv(err)
+    }
+}
+
+/// Reads games from CSV, returning the rating of the player in each game paired with the result.
+///
+/// See the [module documentation](index.html) for the expected columns.
+/// A row that can't be parsed, or that holds an invalid rating or score, is reported
+/// along with its line and column rather than being skipped or corrected.
+pub fn read_games<F: Float + FromStr, R: Read>(reader: R) -> Result<Vec<Game<F>>, CsvError> {
+    let mut reader = Reader::from_reader(reader);
+    let headers = reader.headers()?.clone();
+    let column_index = |column: &'static str| {
+        headers
+            .iter()
+            .position(|header| header.trim() == column)
+            .ok_or(CsvError::MissingColumn { column })
+    };
+    let columns = [
+        column_index(PLAYER_RATING)?,
+        column_index(PLAYER_RD)?,
+        column_index(OPPONENT_RATING)?,
+        column_index(OPPONENT_RD)?,
+        column_index(SCORE)?,
+    ];
+
+    let mut games = Vec::new();
+    for record in reader.records() {
+        let record = record?;
+        let line = line_of(&record);
+        let field =
+            |index: usize, column: &'static str| parse_field(&record, columns[index], column);
+        let rating = |index: usize, value_column, deviation_column| {
+            let value = field(index, value_column)?;
+            let deviation = field(index + 1, deviation_column)?;
+            GlickoRating::try_new(value, deviation).map_err(|error| {
+                let column = match error {
+                    Glicko2Error::NonFinite { field: "value" } => value_column,
+                    _ => deviation_column,
+                };
+                CsvError::InvalidValue {
+                    line,
+                    column,
+                    error,
+                }
+            })
+        };
+        let player = rating(0, PLAYER_RATING, PLAYER_RD)?;
+        let opponent = rating(2, OPPONENT_RATING, OPPONENT_RD)?;
+        let score = Score::custom(field(4, SCORE)?).map_err(|error| CsvError::InvalidValue {
+            line,
+            column: SCORE,
+            error,
+        })?;
+        games.push((player, GameResult::with_score(opponent, score)));
+    }
+    Ok(games)
+}
+
+/// Writes ratings to CSV, with a header row followed by one row per rating.
+///
+/// See the [module documentation](index.html) for the columns written.
+pub fn write_ratings<F: Float + fmt::Display, W: Write>(
+    writer: W,
+    ratings: &[GlickoRating<F>],
+) -> Result<(), CsvError> {
+    let mut writer = Writer::from_writer(writer);
+    writer.write_record([PLAYER_RATING, PLAYER_RD])?;
+    for rating in ratings {
+        writer.write_record([rating.value.to_string(), rating.deviation.to_string()])?;
+    }
+    writer.flush().map_err(csv_crate::Error::from)?;
+    Ok(())
+}
+
+fn parse_field<F: FromStr>(
+    record: &StringRecord,
+    index: usize,
+    column: &'static str,
+) -> Result<F, CsvError> {
+    record
+        .get(index)
+        .and_then(|field| field.trim().parse().ok())
+        .ok_or(CsvError::InvalidNumber {
+            line: line_of(record),
+            column,
+        })
+}
+
+fn line_of(record: &StringRecord) -> u64 {
+    record.position().map_or(0, |position| position.line())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Glicko2Rating;
+
+    #[test]
+    fn test_read_games() {
+        let data = "\
+score,player_rating,player_rd,opponent_rating,opponent_rd
+1,1500,200,1400,30
+0, 1500, 200, 1550, 100
+";
+        let games: Vec<Game> = read_games(data.as_bytes()).unwrap();
+        assert_eq!(games.len(), 2);
+        assert_eq!(
+            games[0].0,
+            GlickoRating {
+                value: 1500.0,
+                deviation: 200.0,
+            }
+        );
+        let expected_opponent = Glicko2Rating::from(GlickoRating {
+            value: 1550.0,
+            deviation: 100.0,
+        });
+        assert_eq!(games[1].1.opponent_value(), expected_opponent.value);
+        assert_eq!(games[1].1.opponent_deviation(), expected_opponent.deviation);
+        assert_eq!(games[1].1.score(), 0.0);
+    }
+
+    #[test]
+    fn test_read_games_errors() {
+        let missing = "player_rating,player_rd,opponent_rating,score\n1500,200,1400,1\n";
+        match read_games::<f64, _>(missing.as_bytes()) {
+            Err(CsvError::MissingColumn { column }) => assert_eq!(column, "opponent_rd"),
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let invalid =
+            "player_rating,player_rd,opponent_rating,opponent_rd,score\n1500,200,1400,30,x\n";
+        match read_games::<f64, _>(invalid.as_bytes()) {
+            Err(CsvError::InvalidNumber { line, column }) => {
+                assert_eq!(line, 2);
+                assert_eq!(column, "score");
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let header = "player_rating,player_rd,opponent_rating,opponent_rd,score\n";
+        let non_finite_value = Glicko2Error::NonFinite { field: "value" };
+        let non_finite_deviation = Glicko2Error::NonFinite { field: "deviation" };
+        let invalid_rows = [
+            ("1500,200,1400,30,7\n", "score", Glicko2Error::InvalidScore),
+            ("1500,200,1400,30,-1\n", "score", Glicko2Error::InvalidScore),
+            ("1500,200,1400,30,NaN\n", "score", Glicko2Error::InvalidScore),
+            ("1500,200,1400,inf,1\n", "opponent_rd", non_finite_deviation),
+            ("1500,NaN,1400,30,1\n", "player_rd", non_finite_deviation),
+            ("1500,-200,1400,30,1\n", "player_rd", Glicko2Error::InvalidDeviation),
+            ("inf,200,1400,30,1\n", "player_rating", non_finite_value),
+        ];
+        for &(row, expected_column, expected_error) in &invalid_rows {
+            let data = [header, row].concat();
+            match read_games::<f64, _>(data.as_bytes()) {
+                Err(CsvError::InvalidValue {
+                    line,
+                    column,
+                    error,
+                }) => {
+                    assert_eq!(line, 2);
+                    assert_eq!(column, expected_column);
+                    assert_eq!(error, expected_error);
+                }
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_write_ratings() {
+        let mut out = Vec::new();
+        let ratings = [
+            GlickoRating {
+                value: 1464.5,
+                deviation: 151.5,
+            },
+            GlickoRating::unrated(),
+        ];
+        write_ratings(&mut out, &ratings).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "player_rating,player_rd\n1464.5,151.5\n1500,350\n"
+        );
+    }
+}
@@ -0,0 +1,119 @@
+//! An implementation of the original [glicko rating system](http://www.glicko.net/glicko/glicko.pdf),
+//! which predates glicko2.
+//!
+//! Glicko lacks the volatility of glicko2, and instead increases the deviation of every rating
+//! by a fixed amount each rating period. This is useful for comparing the two systems on the same data,
+//! or for gradually migrating from one to the other.
+
+use num_traits::Float;
+use math::{e, g};
+use {cast, GameResult, Glicko2Rating, GlickoRating, GLICKO2_SCALE};
+
+/// Calculates a new rating from an existing rating and a series of results using the glicko system.
+///
+/// `c` is the constant on the Glicko scale that governs how much the deviation of a rating grows
+/// each rating period, before the results of that period are taken into account.
+/// As in the glicko paper, the grown deviation is capped at that of an unrated player or team, 350.
+/// If a player has not played in a rating period, this should still be called with an empty slice
+/// so that the new rating deviation for that player is calculated.
+///
+/// The calculation itself is carried out on the Glicko2 scale, where the glicko update
+/// is identical to the glicko2 update with the volatility replaced by `c`.
+pub fn new_rating_glicko1<F: Float>(
+    prior_rating: GlickoRating<F>,
+    results: &[GameResult<F>],
+    c: F,
+) -> GlickoRating<F> {
+    let prior_rating = Glicko2Rating::from(prior_rating);
+    let c = c / cast(GLICKO2_SCALE);
+    let max_deviation = Glicko2Rating::<F>::unrated().deviation;
+    let pre_rd = ((prior_rating.deviation * prior_rating.deviation) + (c * c))
+        .sqrt()
+        .min(max_deviation);
+    if results.is_empty() {
+        return GlickoRating::from(Glicko2Rating {
+            deviation: pre_rd,
+            ..prior_rating
+        });
+    }
+    let v = results
+        .iter()
+        .fold(F::zero(), |acc, result| {
+            let expected = e(
+                prior_rating.value,
+                result.opponent_rating_value,
+                result.opponent_rating_deviation,
+            );
+            acc + result.weight
+                * g(result.opponent_rating_deviation)
+                * g(result.opponent_rating_deviation)
+                * expected
+                * (F::one() - expected)
+        })
+        .recip();
+    let new_rd = ((pre_rd * pre_rd).recip() + v.recip()).sqrt().recip();
+    let new_value = prior_rating.value
+        + (new_rd * new_rd)
+            * results.iter().fold(F::zero(), |acc, result| {
+                acc + result.weight
+                    * g(result.opponent_rating_deviation)
+                    * (result.score
+                        - e(
+                            prior_rating.value,
+                            result.opponent_rating_value,
+                            result.opponent_rating_deviation,
+                        ))
+            });
+    GlickoRating::from(Glicko2Rating {
+        value: new_value,
+        deviation: new_rd,
+        volatility: prior_rating.volatility,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate approx;
+    use self::approx::*;
+    use super::*;
+
+    #[test]
+    fn test_rating_update() {
+        let example_player_rating = GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        };
+        let results = vec![
+            GameResult::win(GlickoRating {
+                value: 1400.0,
+                deviation: 30.0,
+            }),
+            GameResult::loss(GlickoRating {
+                value: 1550.0,
+                deviation: 100.0,
+            }),
+            GameResult::loss(GlickoRating {
+                value: 1700.0,
+                deviation: 300.0,
+            }),
+        ];
+
+        let new_rating = new_rating_glicko1(example_player_rating, &results, 0.0);
+        assert!(Relative::default().epsilon(0.1).eq(&new_rating.value, &1464.1));
+        assert!(Relative::default().epsilon(0.1).eq(&new_rating.deviation, &151.4));
+    }
+
+    #[test]
+    fn test_inactivity() {
+        let rating = GlickoRating {
+            value: 1500.0,
+            deviation: 300.0,
+        };
+        let inactive = new_rating_glicko1(rating, &[], 63.2);
+        assert!(Relative::default().epsilon(0.0001).eq(&inactive.value, &1500.0));
+        assert!(Relative::default().epsilon(0.01).eq(&inactive.deviation, &306.58));
+
+        let capped = new_rating_glicko1(rating, &[], 500.0);
+        assert!(Relative::default().epsilon(0.0001).eq(&capped.deviation, &350.0));
+    }
+}
@@ -1,4 +1,5 @@
 #![deny(missing_docs)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 //! An implementation of the [glicko2 rating system](http://www.glicko.net/glicko/glicko2.pdf). It's a rating system appropriate for rating a team or player and is leveraged by many chess leagues.
 //!
@@ -6,30 +7,334 @@
 //! Then, for each team or player pass their [`Glicko2Rating`](struct.Glicko2Rating) and list of `GameResult`s
 //! to [`new_rating`](fn.new_rating.html) to calculate the new rating for that team or player, which can be saved in place of the old one.
 //! This process is then repeated each rating period.
+//!
+//! All types and calculations are generic over the float type used, which defaults to `f64`.
+//! `f32` can be used instead to halve the memory used by each rating, at the cost of precision.
+//!
+//! The `std` feature is enabled by default. For `no_std` environments, disable default features
+//! and enable the `libm` feature instead, which provides the math functions normally provided by `std`.
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("either the `std` or the `libm` feature must be enabled");
+
+extern crate alloc;
+#[cfg(feature = "chrono")]
+extern crate chrono;
+#[cfg(feature = "std")]
+extern crate core;
+#[cfg(feature = "csv")]
+extern crate csv as csv_crate;
+#[cfg(feature = "heapless")]
+extern crate heapless;
+extern crate num_traits;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "json")]
+extern crate serde_json;
+
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, TimeDelta, Utc};
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::Sum;
+use core::ops::{Add, AddAssign};
+use core::str::FromStr;
+use math::{e, g, volatility_objective};
+use num_traits::{Float, NumCast};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+#[cfg(feature = "json")]
+use serde::de::DeserializeOwned;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "std")]
+use std::error;
+
+#[cfg(feature = "compact")]
+pub mod compact;
+#[cfg(feature = "csv")]
+pub mod csv;
+pub mod glicko1;
+pub mod math;
+#[cfg(feature = "experimental")]
+pub mod opponent_volatility;
+#[cfg(feature = "simulate")]
+pub mod simulate;
+pub mod tiers;
+
+/// A reasonable default for the system constant τ passed to [`new_rating`](fn.new_rating.html).
+///
+/// This is the value used in the example in the glicko2 paper. Glickman suggests that reasonable choices
+/// are between 0.3 and 1.2, and that the system should be tested to find the most predictive value.
+pub const DEFAULT_SYS_CONSTANT: f64 = 0.5;
+
+/// The convergence tolerance of the iterative volatility calculation used by [`new_rating`](fn.new_rating.html).
+///
+/// This is the value suggested in the glicko2 paper. Other tolerances can be used with
+/// [`new_rating_with_tolerance`](fn.new_rating_with_tolerance.html).
+/// The tolerance is never finer than the precision of the float type in use allows,
+/// which matters for `f32` ratings with small volatilities.
+pub const CONVERGENCE_TOLERANCE: f64 = 0.000001;
+
+/// A common threshold on the Glicko scale for [`GlickoRating::is_provisional`](struct.GlickoRating.html#method.is_provisional).
+///
+/// Ratings with a deviation above this are uncertain enough that they are commonly marked as provisional.
+pub const PROVISIONAL_DEVIATION: f64 = 110.0;
+
+/// The draw rate used by [`outcome_probabilities`](fn.outcome_probabilities.html), through the
+/// default [`DrawModel`](enum.DrawModel.html), `DrawModel::NoDraws`.
+///
+/// Glicko2 doesn't model draws, so by default no probability is assigned to them.
+pub const DEFAULT_DRAW_RATE: f64 = 0.0;
+
+// The standard conversion between the Glicko and Glicko2 scales, and the defaults for unrated ratings.
+const GLICKO_BASE: f64 = 1500.0;
+const GLICKO2_SCALE: f64 = 173.7178;
+const UNRATED_DEVIATION: f64 = 350.0;
+const DEFAULT_VOLATILITY: f64 = 0.06;
+
+// The bounds enforced by `Glicko2Rating::sanitized`, on the Glicko2 scale.
+// `MIN_DEVIATION` is also the smallest prior deviation used by `new_rating`.
+const MIN_DEVIATION: f64 = 0.000001;
+const MIN_VOLATILITY: f64 = 0.000001;
+const MAX_VOLATILITY: f64 = 1.0;
 
-const CONVERGENCE_TOLERANCE: f64 = 0.000001;
+const MAX_ITERATIONS: u32 = 10_000;
+// Far beyond the recommended range of 0.3 to 1.2. Much larger system constants let the volatility
+// collapse towards zero after a single rating period.
+const MAX_SYS_CONSTANT: f64 = 10.0;
+// The number of iterations of the Illinois algorithm over which the bracket must halve in width,
+// before falling back to bisection.
+const STALL_ITERATIONS: u32 = 8;
+
+// The bounds on the estimated variance `v`, which keep the update finite for degenerate results.
+const MIN_VARIANCE: f64 = 1e-12;
+const MAX_VARIANCE: f64 = 1e12;
 
 /// Represents the rating of a player or team on the Glicko2 scale.
-#[derive(Clone, Copy, Debug)]
-pub struct Glicko2Rating {
+///
+/// Ratings compare equal only if every field is exactly equal, with the usual caveats of float equality.
+/// To order ratings, for example to build a leaderboard, see [`cmp_by_value`](#method.cmp_by_value).
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Glicko2Rating<F = f64> {
     /// The estimated skill of the team or player.
-    pub value: f64,
+    pub value: F,
     /// The uncertainty of the rating value - a standard deviation, in statistical terms.
-    pub deviation: f64,
+    pub deviation: F,
     /// The degree of expected fluctuation in a rating - this enhancement differentiates Glicko2 to Glicko.
-    pub volatility: f64,
+    pub volatility: F,
 }
 
 /// Represents the rating of a player or team on the Glicko (not Glicko2) scale.
 ///
 /// Glicko2 rating numbers tend to be less friendly for humans,
 /// so it's common to convert ratings to the Glicko scale before display.
-#[derive(Clone, Copy, Debug)]
-pub struct GlickoRating {
+///
+/// Ratings compare equal only if every field is exactly equal, with the usual caveats of float equality.
+/// To order ratings, for example to build a leaderboard, see [`cmp_by_value`](#method.cmp_by_value).
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GlickoRating<F = f64> {
     /// The estimated skill of the team or player.
-    pub value: f64,
+    pub value: F,
     /// The uncertainty of the rating value - a standard deviation, in statistical terms.
-    pub deviation: f64,
+    pub deviation: F,
+}
+
+/// An error returned by the fallible functions of this crate,
+/// such as [`try_new_rating`](fn.try_new_rating.html) and
+/// [`Glicko2Rating::try_new`](struct.Glicko2Rating.html#method.try_new).
+///
+/// More variants may be added in the future, so matches must include a wildcard arm.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Glicko2Error {
+    /// The iterative volatility calculation failed to converge within the allowed number of iterations.
+    NonConvergence {
+        /// The number of iterations that were performed before giving up.
+        iterations: u32,
+    },
+    /// The named field was NaN or infinite.
+    NonFinite {
+        /// The name of the field that was not finite.
+        field: &'static str,
+    },
+    /// A rating deviation was negative, or was zero when converting between scales.
+    InvalidDeviation,
+    /// A score was NaN or outside of the range [0.0, 1.0].
+    InvalidScore,
+    /// A rating volatility was zero or negative.
+    InvalidVolatility,
+    /// The system constant was NaN, zero or negative, or larger than 10.
+    InvalidSysConstant,
+    /// The convergence tolerance was NaN, zero or negative.
+    InvalidTolerance,
+}
+
+impl fmt::Display for Glicko2Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Glicko2Error::NonConvergence { iterations } => write!(
+                f,
+                "volatility calculation failed to converge after {} iterations",
+                iterations
+            ),
+            Glicko2Error::NonFinite { field } => write!(f, "{} is not finite", field),
+            Glicko2Error::InvalidDeviation => write!(f, "rating deviation is out of range"),
+            Glicko2Error::InvalidScore => write!(f, "score is not between 0 and 1"),
+            Glicko2Error::InvalidVolatility => write!(f, "rating volatility is not positive"),
+            Glicko2Error::InvalidSysConstant => {
+                write!(f, "system constant is not between 0 and {}", MAX_SYS_CONSTANT)
+            }
+            Glicko2Error::InvalidTolerance => write!(f, "convergence tolerance is not positive"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for Glicko2Error {}
+
+/// A wrapper around a `Glicko2Rating` that implements `Eq` and `Hash`,
+/// so that ratings can be used as keys in hash maps, for example to memoize rating calculations.
+///
+/// Unlike `Glicko2Rating`, which compares fields as floats, two `HashableRating`s are equal
+/// only if every field has exactly the same bit pattern. This means that `0.0` and `-0.0` are not equal,
+/// while NaNs with the same bit pattern are.
+#[derive(Clone, Copy, Debug)]
+pub struct HashableRating<F = f64>(pub Glicko2Rating<F>);
+
+impl<F: Float> HashableRating<F> {
+    fn bits(&self) -> [(u64, i16, i8); 3] {
+        [
+            self.0.value.integer_decode(),
+            self.0.deviation.integer_decode(),
+            self.0.volatility.integer_decode(),
+        ]
+    }
+}
+
+impl<F: Float> PartialEq for HashableRating<F> {
+    fn eq(&self, other: &HashableRating<F>) -> bool {
+        self.bits() == other.bits()
+    }
+}
+
+impl<F: Float> Eq for HashableRating<F> {}
+
+impl<F: Float> Hash for HashableRating<F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bits().hash(state);
+    }
+}
+
+impl<F> From<Glicko2Rating<F>> for HashableRating<F> {
+    fn from(rating: Glicko2Rating<F>) -> HashableRating<F> {
+        HashableRating(rating)
+    }
+}
+
+impl<F> From<HashableRating<F>> for Glicko2Rating<F> {
+    fn from(rating: HashableRating<F>) -> Glicko2Rating<F> {
+        rating.0
+    }
+}
+
+/// An error that can occur while parsing a rating from a string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseRatingError {
+    /// The string did not have the expected number of `/` separated fields.
+    WrongFieldCount {
+        /// The number of fields that were expected.
+        expected: usize,
+        /// The number of fields that were found.
+        found: usize,
+    },
+    /// The named field could not be parsed as a number.
+    InvalidNumber {
+        /// The name of the field that could not be parsed.
+        field: &'static str,
+    },
+}
+
+impl fmt::Display for ParseRatingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseRatingError::WrongFieldCount { expected, found } => write!(
+                f,
+                "expected {} `/` separated fields, found {}",
+                expected, found
+            ),
+            ParseRatingError::InvalidNumber { field } => {
+                write!(f, "rating {} is not a valid number", field)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for ParseRatingError {}
+
+/// The outcome of a game from the perspective of the player or team being rated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// The player or team won the game.
+    Win,
+    /// The player or team lost the game.
+    Loss,
+    /// The game was a draw.
+    Draw,
+}
+
+/// The score of a game from the perspective of the player or team being rated,
+/// which is always in the range [0.0, 1.0].
+///
+/// 1.0 is a win, 0.0 is a loss, and 0.5 is a draw. Other scores within the range can be
+/// constructed with [`custom`](#method.custom), and scores for `f32` can be constructed
+/// from an [`Outcome`](enum.Outcome.html).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Score<F = f64>(F);
+
+impl Score<f64> {
+    /// The score of a win.
+    pub const WIN: Score<f64> = Score(1.0);
+    /// The score of a draw.
+    pub const DRAW: Score<f64> = Score(0.5);
+    /// The score of a loss.
+    pub const LOSS: Score<f64> = Score(0.0);
+}
+
+impl<F: Float> Score<F> {
+    /// Constructs an arbitrary score, such as for a match decided on a tiebreak.
+    ///
+    /// Returns `Glicko2Error::InvalidScore` if `score` is outside of the range [0.0, 1.0], or is NaN.
+    pub fn custom(score: F) -> Result<Score<F>, Glicko2Error> {
+        if score >= F::zero() && score <= F::one() {
+            Ok(Score(score))
+        } else {
+            Err(Glicko2Error::InvalidScore)
+        }
+    }
+
+    /// Returns the score as a number.
+    pub fn value(self) -> F {
+        self.0
+    }
+}
+
+impl<F: Float> From<Outcome> for Score<F> {
+    fn from(outcome: Outcome) -> Score<F> {
+        match outcome {
+            Outcome::Win => Score(F::one()),
+            Outcome::Loss => Score(F::zero()),
+            Outcome::Draw => Score(cast(0.5)),
+        }
+    }
 }
 
 /// Represents a result (win, loss, or draw) over an opposing player or team.
@@ -38,42 +343,106 @@ pub struct GlickoRating {
 /// The player that actually won, lost or drew respectively is not stored
 /// in the game result, but instead is passed in to [`new_rating`](fn.new_rating.html).
 #[derive(Clone, Copy, Debug)]
-pub struct GameResult {
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "F: Float + Deserialize<'de>"))
+)]
+pub struct GameResult<F = f64> {
     // GLICKO2
-    opponent_rating_value: f64,
-    opponent_rating_deviation: f64,
-    score: f64,
+    opponent_rating_value: F,
+    opponent_rating_deviation: F,
+    score: F,
+    #[cfg_attr(feature = "serde", serde(default = "default_weight"))]
+    weight: F,
 }
 
-impl GameResult {
-    /// Constructs a new game result representing a win over a player or team
+#[cfg(feature = "serde")]
+fn default_weight<F: Float>() -> F {
+    F::one()
+}
+
+impl<F: Float> GameResult<F> {
+    /// Constructs a new game result with an arbitrary `score` against a player or team
+    /// with rating `opponent_rating`.
+    ///
+    /// `score` must be in the range [0.0, 1.0], where 1.0 is a win, 0.0 is a loss,
+    /// and 0.5 is a draw. Scores outside of this range are clamped into it, and a score of NaN,
+    /// which says nothing about who won, is treated as a draw. Use
+    /// [`Score::custom`](struct.Score.html#method.custom) with [`with_score`](#method.with_score)
+    /// to reject such scores instead.
+    /// This is useful for games with partial-credit outcomes, such as a match decided on a tiebreak.
+    ///
+    /// A `Glicko2Rating` or `GlickoRating` can be supplied for `opponent_rating`,
+    /// and it will not affect the result of rating calculations
+    /// as the volatility of opponents are not looked at for updating ratings.
+    pub fn new<T: Into<Glicko2Rating<F>>>(opponent_rating: T, score: F) -> GameResult<F> {
+        let score = if score.is_nan() {
+            cast(0.5)
+        } else {
+            num_traits::clamp(score, F::zero(), F::one())
+        };
+        GameResult::with_score(opponent_rating, Score(score))
+    }
+
+    /// Constructs a new game result with `score` against a player or team
     /// with rating `opponent_rating`.
     ///
     /// A `Glicko2Rating` or `GlickoRating` can be supplied for `opponent_rating`,
     /// and it will not affect the result of rating calculations
     /// as the volatility of opponents are not looked at for updating ratings.
-    pub fn win<T: Into<Glicko2Rating>>(opponent_rating: T) -> GameResult {
-        let opponent_glicko2: Glicko2Rating = opponent_rating.into();
+    pub fn with_score<T: Into<Glicko2Rating<F>>>(
+        opponent_rating: T,
+        score: Score<F>,
+    ) -> GameResult<F> {
+        let opponent_glicko2: Glicko2Rating<F> = opponent_rating.into();
         GameResult {
             opponent_rating_value: opponent_glicko2.value,
             opponent_rating_deviation: opponent_glicko2.deviation,
-            score: 1.0,
+            score: score.value(),
+            weight: F::one(),
+        }
+    }
+
+    /// Constructs a new game result with an arbitrary `score` against a player or team
+    /// with rating `opponent_rating`, which counts for `weight` games.
+    ///
+    /// Game results constructed any other way have a weight of 1.0. A smaller weight reduces
+    /// the influence of the game on the new rating, which is useful for decaying the impact of
+    /// older games within a rating period. A weight of 0.0 makes the game have no influence at all,
+    /// and negative weights are treated as 0.0.
+    ///
+    /// As with [`new`](#method.new), `score` is clamped into the range [0.0, 1.0],
+    /// and a score of NaN is treated as a draw.
+    pub fn weighted<T: Into<Glicko2Rating<F>>>(
+        opponent_rating: T,
+        score: F,
+        weight: F,
+    ) -> GameResult<F> {
+        GameResult {
+            weight: weight.max(F::zero()),
+            ..GameResult::new(opponent_rating, score)
         }
     }
 
+    /// Constructs a new game result representing a win over a player or team
+    /// with rating `opponent_rating`.
+    ///
+    /// A `Glicko2Rating` or `GlickoRating` can be supplied for `opponent_rating`,
+    /// and it will not affect the result of rating calculations
+    /// as the volatility of opponents are not looked at for updating ratings.
+    pub fn win<T: Into<Glicko2Rating<F>>>(opponent_rating: T) -> GameResult<F> {
+        GameResult::with_score(opponent_rating, Score::from(Outcome::Win))
+    }
+
     /// Constructs a new game result representing a loss to a player or team
     /// with rating `opponent_rating`.
     ///
     /// A `Glicko2Rating` or `GlickoRating` can be supplied for `opponent_rating`,
     /// and it will not affect the result of rating calculations
     /// as the volatility of opponents are not looked at for updating ratings.
-    pub fn loss<T: Into<Glicko2Rating>>(opponent_rating: T) -> GameResult {
-        let opponent_glicko2: Glicko2Rating = opponent_rating.into();
-        GameResult {
-            opponent_rating_value: opponent_glicko2.value,
-            opponent_rating_deviation: opponent_glicko2.deviation,
-            score: 0.0,
-        }
+    pub fn loss<T: Into<Glicko2Rating<F>>>(opponent_rating: T) -> GameResult<F> {
+        GameResult::with_score(opponent_rating, Score::from(Outcome::Loss))
     }
 
     /// Constructs a new game result representing a draw with a player or team
@@ -82,285 +451,4806 @@ impl GameResult {
     /// A `Glicko2Rating` or `GlickoRating` can be supplied for `opponent_rating`,
     /// and it will not affect the result of rating calculations
     /// as the volatility of opponents are not looked at for updating ratings.
-    pub fn draw<T: Into<Glicko2Rating>>(opponent_rating: T) -> GameResult {
-        let opponent_glicko2: Glicko2Rating = opponent_rating.into();
-        GameResult {
-            opponent_rating_value: opponent_glicko2.value,
-            opponent_rating_deviation: opponent_glicko2.deviation,
-            score: 0.5,
+    pub fn draw<T: Into<Glicko2Rating<F>>>(opponent_rating: T) -> GameResult<F> {
+        GameResult::with_score(opponent_rating, Score::from(Outcome::Draw))
+    }
+
+    /// Constructs a new game result representing a win over, if `won` is true,
+    /// or otherwise a loss to, a player or team with rating `opponent_rating`.
+    ///
+    /// This is convenient for games without draws.
+    pub fn from_bool<T: Into<Glicko2Rating<F>>>(opponent_rating: T, won: bool) -> GameResult<F> {
+        if won {
+            GameResult::win(opponent_rating)
+        } else {
+            GameResult::loss(opponent_rating)
         }
     }
-}
 
-impl From<GlickoRating> for Glicko2Rating {
-    fn from(rating: GlickoRating) -> Glicko2Rating {
-        Glicko2Rating {
-            value: (rating.value - 1500.0) / 173.7178,
-            deviation: rating.deviation / 173.7178,
-            volatility: 0.06,
+    /// Constructs a new game result against a player or team with rating `opponent_rating`
+    /// from the comparison of the player or team being rated to the opponent.
+    ///
+    /// `Ordering::Greater` is a win, `Ordering::Equal` is a draw, and `Ordering::Less` is a loss.
+    /// This is convenient when outcomes are decided by comparing scores, such as with `Ord::cmp`.
+    pub fn from_ordering<T: Into<Glicko2Rating<F>>>(
+        opponent_rating: T,
+        ordering: Ordering,
+    ) -> GameResult<F> {
+        match ordering {
+            Ordering::Greater => GameResult::win(opponent_rating),
+            Ordering::Equal => GameResult::draw(opponent_rating),
+            Ordering::Less => GameResult::loss(opponent_rating),
         }
     }
-}
 
-impl From<Glicko2Rating> for GlickoRating {
-    fn from(rating: Glicko2Rating) -> GlickoRating {
-        GlickoRating {
-            value: rating.value * 173.7178 + 1500.0,
-            deviation: rating.deviation * 173.7178,
+    /// Returns the rating value of the opponent, on the Glicko2 scale.
+    pub fn opponent_value(&self) -> F {
+        self.opponent_rating_value
+    }
+
+    /// Returns the rating deviation of the opponent, on the Glicko2 scale.
+    pub fn opponent_deviation(&self) -> F {
+        self.opponent_rating_deviation
+    }
+
+    /// Returns the score of the game, where 1.0 is a win, 0.0 is a loss, and 0.5 is a draw.
+    pub fn score(&self) -> F {
+        self.score
+    }
+
+    /// Returns the weight of the game. See [`weighted`](#method.weighted).
+    pub fn weight(&self) -> F {
+        self.weight
+    }
+
+    /// Constructs a game result for each outcome and opponent rating pair in `outcomes`.
+    ///
+    /// This is convenient for mapping rows of game data straight into results.
+    pub fn from_outcomes<T, I>(outcomes: I) -> Vec<GameResult<F>>
+    where
+        T: Into<Glicko2Rating<F>>,
+        I: IntoIterator<Item = (Outcome, T)>,
+    {
+        outcomes
+            .into_iter()
+            .map(|(outcome, opponent_rating)| {
+                GameResult::with_score(opponent_rating, Score::from(outcome))
+            })
+            .collect()
+    }
+
+    /// Constructs a game result for each opponent rating and score pair in `scores`.
+    ///
+    /// This is convenient for games with precomputed scores, such as the fraction of sets won
+    /// in a tennis match. Returns `Glicko2Error::InvalidScore` if any score is outside of the range
+    /// [0.0, 1.0], as checked by [`Score::custom`](struct.Score.html#method.custom).
+    pub fn from_scored<T, I>(scores: I) -> Result<Vec<GameResult<F>>, Glicko2Error>
+    where
+        T: Into<Glicko2Rating<F>>,
+        I: IntoIterator<Item = (T, F)>,
+    {
+        scores
+            .into_iter()
+            .map(|(opponent_rating, score)| {
+                Score::custom(score).map(|score| GameResult::with_score(opponent_rating, score))
+            })
+            .collect()
+    }
+
+    /// Constructs game results from head-to-head records, each pairing an opponent rating with
+    /// the number of `(wins, draws, losses)` against that opponent.
+    ///
+    /// Every record is expanded into that many wins, then draws, then losses against the opponent.
+    /// This is the natural shape of aggregated game data, such as the result of a `GROUP BY` query,
+    /// or a `HashMap` keyed by [`HashableRating`](struct.HashableRating.html),
+    /// which can be passed in directly.
+    pub fn from_records<T, I>(records: I) -> Vec<GameResult<F>>
+    where
+        T: Into<Glicko2Rating<F>>,
+        I: IntoIterator<Item = (T, (u32, u32, u32))>,
+    {
+        let mut results = Vec::new();
+        for (opponent_rating, (wins, draws, losses)) in records {
+            let opponent_rating = opponent_rating.into();
+            for &(outcome, count) in &[
+                (Outcome::Win, wins),
+                (Outcome::Draw, draws),
+                (Outcome::Loss, losses),
+            ] {
+                for _ in 0..count {
+                    results.push(GameResult::with_score(opponent_rating, Score::from(outcome)));
+                }
+            }
         }
+        results
     }
 }
 
-impl Glicko2Rating {
-    /// Constructs a `Glicko2Rating` using the defaults for a new (unrated) player or team.
-    pub fn unrated() -> Glicko2Rating {
-        Glicko2Rating::from(GlickoRating::unrated())
-    }
+/// The parameters of a conversion between the Glicko2 scale and a Glicko-like display scale.
+///
+/// A value of 0 on the Glicko2 scale corresponds to `base` on the display scale,
+/// and a difference of 1 on the Glicko2 scale corresponds to a difference of `scale` on the display scale.
+/// The standard Glicko scale has a base of 1500 and a scale of 173.7178.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScaleParams<F = f64> {
+    /// The display value of a Glicko2 value of 0.
+    pub base: F,
+    /// The display difference corresponding to a Glicko2 difference of 1.
+    pub scale: F,
 }
 
-impl GlickoRating {
-    /// Constructs a `GlickoRating` using the defaults for a new (unrated) player or team.
-    pub fn unrated() -> GlickoRating {
-        GlickoRating {
-            value: 1500.0,
-            deviation: 350.0,
+impl<F: Float> ScaleParams<F> {
+    /// Constructs the `ScaleParams` of the standard Glicko scale,
+    /// as used by the `From` conversions between `GlickoRating` and `Glicko2Rating`.
+    pub fn standard() -> ScaleParams<F> {
+        ScaleParams {
+            base: cast(GLICKO_BASE),
+            scale: cast(GLICKO2_SCALE),
         }
     }
 }
 
-impl Default for Glicko2Rating {
-    fn default() -> Glicko2Rating {
-        Glicko2Rating::unrated()
+impl<F: Float> Default for ScaleParams<F> {
+    fn default() -> ScaleParams<F> {
+        ScaleParams::standard()
     }
 }
 
-impl Default for GlickoRating {
-    fn default() -> GlickoRating {
-        GlickoRating::unrated()
+/// Converts a rating on the display scale described by `params` to the Glicko2 scale.
+///
+/// As with the `From<GlickoRating>` impl, the volatility is set to the default of 0.06.
+pub fn to_glicko2_scaled<F: Float>(
+    rating: GlickoRating<F>,
+    params: ScaleParams<F>,
+) -> Glicko2Rating<F> {
+    Glicko2Rating {
+        value: (rating.value - params.base) / params.scale,
+        deviation: rating.deviation / params.scale,
+        volatility: cast(DEFAULT_VOLATILITY),
     }
 }
 
-// The rest is best read with a copy of the glicko2 example PDF;
-// I've tried to keep naming somewhat consistent
-// http://www.glicko.net/glicko/glicko2.pdf
-// One difference is that what is referred to in the pdf as 'player'
-// I am referring to as a `rating`, and what is referred to as `rating`
-// I am referring to as a `value`. I think that these changes make
-// the API more clear, hopefully it's not too confusing.
-
-fn g(rating_deviation: f64) -> f64 {
-    use std::f64::consts::PI;
-    let denom = 1.0 + ((3.0 * rating_deviation * rating_deviation) / (PI * PI));
-    denom.sqrt().recip()
+/// Converts a rating on the Glicko2 scale to the display scale described by `params`.
+pub fn to_glicko_scaled<F: Float>(
+    rating: Glicko2Rating<F>,
+    params: ScaleParams<F>,
+) -> GlickoRating<F> {
+    GlickoRating {
+        value: rating.value * params.scale + params.base,
+        deviation: rating.deviation * params.scale,
+    }
 }
 
-fn e(rating: f64, other_rating: f64, other_rating_deviation: f64) -> f64 {
-    let base = -1.0 * g(other_rating_deviation) * (rating - other_rating);
-    (1.0 + base.exp()).recip()
+/// Converts every rating in `ratings` to the Glicko scale, such as for exporting a leaderboard.
+///
+/// This is equivalent to converting each rating with the `From<Glicko2Rating>` impl.
+pub fn to_glicko_all<F: Float>(ratings: &[Glicko2Rating<F>]) -> Vec<GlickoRating<F>> {
+    ratings.iter().map(|&rating| GlickoRating::from(rating)).collect()
 }
 
-fn f(x: f64, delta: f64, rating_deviation: f64, v: f64, volatility: f64, sys_constant: f64) -> f64 {
-    let fraction_one = {
-        let numer =
-            x.exp() * ((delta * delta) - (rating_deviation * rating_deviation) - v - x.exp());
-        let denom = 2.0 * (rating_deviation * rating_deviation + v + x.exp())
-            * (rating_deviation * rating_deviation + v + x.exp());
-        numer / denom
-    };
-    let fraction_two = {
-        let numer = x - (volatility * volatility).ln();
-        let denom = sys_constant * sys_constant;
-        numer / denom
-    };
-    fraction_one - fraction_two
+/// Converts every rating in `ratings` to the Glicko2 scale.
+///
+/// This is equivalent to converting each rating with the `From<GlickoRating>` impl,
+/// so every volatility is set to the default of 0.06.
+pub fn to_glicko2_all<F: Float>(ratings: &[GlickoRating<F>]) -> Vec<Glicko2Rating<F>> {
+    ratings.iter().map(|&rating| Glicko2Rating::from(rating)).collect()
 }
 
-/// Calculates a new rating from an existing rating and a series of results.
-///
-/// If a player has not played in a rating period, new_rating should still be called
-/// with an empty slice so that the new rating deviation for that player is calculated.
+/// Converts a rating on the Glicko scale to the Glicko2 scale, with the default volatility of 0.06.
 ///
-/// Unlike `GameResult`s, which can be constructed with a `Glicko2Rating` or a`GlickoRating`,
-/// `new_rating` requires a `Glicko2Rating`. This is because the volatility field present only in
-/// `Glicko2Rating` affects the result of the calculation. Using a default volatility can be done,
-/// but must be made explicit at the call site using the `Into<GlickoRating>` impl.
-/// Similarly, converting the final result back to a `GlickoRating` and thus losing data is left to
-/// the caller. Generally, converting back to a `GlickoRating` is only needed for display purposes.
+/// Converting back with the `From<Glicko2Rating>` impl for `GlickoRating` recovers the value and
+/// deviation up to floating point rounding: for `f64`, to within 0.000000001 for values between
+/// 0 and 4000 and deviations between 1 and 700, and for `f32`, to within 0.001.
+/// Converting back and forth repeatedly doesn't accumulate error beyond this.
+impl<F: Float> From<GlickoRating<F>> for Glicko2Rating<F> {
+    fn from(rating: GlickoRating<F>) -> Glicko2Rating<F> {
+        to_glicko2_scaled(rating, ScaleParams::standard())
+    }
+}
+
+/// Converts a rating on the Glicko2 scale to the Glicko scale, discarding the volatility.
 ///
-/// `sys_constant` is best explained in the words of Mark Glickman himself:
-/// > The system constant, τ, which constrains the change in volatility over time, needs to be
-/// > set prior to application of the system. Reasonable choices are between 0.3 and 1.2,
-/// > though the system should be tested to decide which value results in greatest predictive
-/// > accuracy. Smaller values of τ prevent the volatility measures from changing by large
-/// > amounts, which in turn prevent enormous changes in ratings based on very improbable
-/// > results.
-pub fn new_rating(
-    prior_rating: Glicko2Rating,
-    results: &[GameResult],
-    sys_constant: f64,
-) -> Glicko2Rating {
-    if !results.is_empty() {
-        let v: f64 = {
-            results
-                .iter()
-                .fold(0.0, |acc, result| {
-                    acc
-                        + g(result.opponent_rating_deviation) * g(result.opponent_rating_deviation)
-                            * e(
-                                prior_rating.value,
-                                result.opponent_rating_value,
-                                result.opponent_rating_deviation,
-                            )
-                            * (1.0
-                                - e(
-                                    prior_rating.value,
-                                    result.opponent_rating_value,
-                                    result.opponent_rating_deviation,
-                                ))
-                })
-                .recip()
-        };
-        let delta = {
-            v * results.iter().fold(0.0, |acc, result| {
-                acc
-                    + g(result.opponent_rating_deviation)
-                        * (result.score
-                            - e(
-                                prior_rating.value,
-                                result.opponent_rating_value,
-                                result.opponent_rating_deviation,
-                            ))
-            })
-        };
-        let new_volatility = {
-            let mut a = (prior_rating.volatility * prior_rating.volatility).ln();
-            let delta_squared = delta * delta;
-            let rd_squared = prior_rating.deviation * prior_rating.deviation;
-            let mut b = if delta_squared > rd_squared + v {
-                (delta_squared - rd_squared - v).ln()
-            } else {
-                let mut k = 1.0;
-                while f(
-                    a - k * sys_constant,
-                    delta,
-                    prior_rating.deviation,
-                    v,
-                    prior_rating.volatility,
-                    sys_constant,
-                ) < 0.0
-                {
-                    k += 1.0;
-                }
-                a - k * sys_constant
-            };
-            let mut fa = f(
-                a,
-                delta,
-                prior_rating.deviation,
-                v,
-                prior_rating.volatility,
-                sys_constant,
-            );
-            let mut fb = f(
-                b,
-                delta,
-                prior_rating.deviation,
-                v,
-                prior_rating.volatility,
-                sys_constant,
-            );
-            while (b - a).abs() > CONVERGENCE_TOLERANCE {
-                // a
-                let c = a + ((a - b) * fa / (fb - fa));
-                let fc = f(
-                    c,
-                    delta,
-                    prior_rating.deviation,
-                    v,
-                    prior_rating.volatility,
-                    sys_constant,
-                );
-                // b
-                if fc * fb <= 0.0 {
-                    a = b;
-                    fa = fb;
-                } else {
-                    fa /= 2.0;
-                }
-                // c
-                b = c;
-                fb = fc;
-                // d (while loop)
-            }
-            (a / 2.0).exp()
-        };
-        let new_pre_rd = ((prior_rating.deviation * prior_rating.deviation)
-            + (new_volatility * new_volatility))
-            .sqrt();
-        let new_rd = {
-            let subexpr_1 = (new_pre_rd * new_pre_rd).recip();
-            let subexpr_2 = v.recip();
-            (subexpr_1 + subexpr_2).sqrt().recip()
-        };
-        let new_rating = {
-            prior_rating.value + ((new_rd * new_rd) * results.iter().fold(0.0, |acc, &result| {
-                acc
-                    + g(result.opponent_rating_deviation)
-                        * (result.score
-                            - e(
-                                prior_rating.value,
-                                result.opponent_rating_value,
-                                result.opponent_rating_deviation,
-                            ))
-            }))
-        };
-        Glicko2Rating {
-            value: new_rating,
-            deviation: new_rd,
-            volatility: new_volatility,
-        }
-    } else {
-        let new_rd = ((prior_rating.deviation * prior_rating.deviation)
-            + (prior_rating.volatility * prior_rating.volatility))
-            .sqrt();
+/// See the `From<GlickoRating>` impl for `Glicko2Rating` for the precision of round trips.
+/// To keep the volatility, see
+/// [`Glicko2Rating::to_glicko_preserving`](struct.Glicko2Rating.html#method.to_glicko_preserving).
+impl<F: Float> From<Glicko2Rating<F>> for GlickoRating<F> {
+    fn from(rating: Glicko2Rating<F>) -> GlickoRating<F> {
+        to_glicko_scaled(rating, ScaleParams::standard())
+    }
+}
+
+impl<'a, F: Float> From<&'a Glicko2Rating<F>> for Glicko2Rating<F> {
+    fn from(rating: &'a Glicko2Rating<F>) -> Glicko2Rating<F> {
+        *rating
+    }
+}
+
+impl<'a, F: Float> From<&'a GlickoRating<F>> for Glicko2Rating<F> {
+    fn from(rating: &'a GlickoRating<F>) -> Glicko2Rating<F> {
+        Glicko2Rating::from(*rating)
+    }
+}
+
+/// Constructs a `GlickoRating` from a `(value, deviation)` tuple.
+impl<F> From<(F, F)> for GlickoRating<F> {
+    fn from((value, deviation): (F, F)) -> GlickoRating<F> {
+        GlickoRating { value, deviation }
+    }
+}
+
+/// Constructs a `Glicko2Rating` from a `(value, deviation, volatility)` tuple.
+impl<F> From<(F, F, F)> for Glicko2Rating<F> {
+    fn from((value, deviation, volatility): (F, F, F)) -> Glicko2Rating<F> {
         Glicko2Rating {
-            value: prior_rating.value,
-            deviation: new_rd,
-            volatility: prior_rating.volatility,
+            value,
+            deviation,
+            volatility,
         }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    extern crate approx;
-    use self::approx::*;
-    use super::*;
+impl<F: Float> Glicko2Rating<F> {
+    /// Constructs a `Glicko2Rating` from its fields.
+    ///
+    /// This is a `const fn`, so it can be used to declare known ratings as constants or statics.
+    /// The fields are not checked; see [`try_new`](#method.try_new) for a checked constructor.
+    pub const fn new(value: F, deviation: F, volatility: F) -> Glicko2Rating<F> {
+        Glicko2Rating {
+            value,
+            deviation,
+            volatility,
+        }
+    }
 
-    #[test]
-    fn test_rating_update() {
-        let example_player_rating = Glicko2Rating::from(GlickoRating {
-            value: 1500.0,
-            deviation: 200.0,
-        });
-        let mut results = vec![];
-        results.push(GameResult::win(GlickoRating {
-            value: 1400.0,
-            deviation: 30.0,
-        }));
-        results.push(GameResult::loss(GlickoRating {
-            value: 1550.0,
-            deviation: 100.0,
-        }));
-        results.push(GameResult::loss(GlickoRating {
-            value: 1700.0,
-            deviation: 300.0,
-        }));
+    /// Constructs a `Glicko2Rating`, checking that the supplied fields are valid.
+    ///
+    /// Returns an error if any field is NaN or infinite, if `deviation` is negative,
+    /// or if `volatility` is zero or negative. Such ratings would otherwise
+    /// silently produce NaN or nonsensical results from [`new_rating`](fn.new_rating.html).
+    pub fn try_new(
+        value: F,
+        deviation: F,
+        volatility: F,
+    ) -> Result<Glicko2Rating<F>, Glicko2Error> {
+        validate_value_and_deviation(value, deviation)?;
+        if !volatility.is_finite() {
+            return Err(Glicko2Error::NonFinite { field: "volatility" });
+        }
+        if volatility <= F::zero() {
+            return Err(Glicko2Error::InvalidVolatility);
+        }
+        Ok(Glicko2Rating {
+            value,
+            deviation,
+            volatility,
+        })
+    }
 
-        let new_rating = new_rating(example_player_rating, &results, 0.5);
-        assert!(Relative::default().epsilon(0.0001).eq(&new_rating.value, &-0.2069));
-        assert!(Relative::default().epsilon(0.0001).eq(&new_rating.deviation, &0.8722));
-        assert!(Relative::default().epsilon(0.0001).eq(&new_rating.volatility, &0.05999))
+    /// Returns whether this rating satisfies the invariants of the crate: every field is finite,
+    /// the deviation is zero or positive, and the volatility is positive.
+    ///
+    /// These are exactly the conditions checked by [`try_new`](#method.try_new).
+    /// Every rating returned by [`new_rating`](fn.new_rating.html) for a valid prior rating and
+    /// valid results is valid, so this is useful for asserting as much in tests,
+    /// such as property tests with proptest or quickcheck.
+    pub fn is_valid(&self) -> bool {
+        Glicko2Rating::try_new(self.value, self.deviation, self.volatility).is_ok()
+    }
+
+    /// Constructs a `Glicko2Rating` from a rating on the Glicko scale and a volatility.
+    ///
+    /// The value and deviation are converted to the Glicko2 scale as in the `From<GlickoRating>` impl,
+    /// but rather than assuming a volatility of 0.06, the supplied `volatility` is used.
+    /// This is useful for restoring a rating stored as a Glicko scale value and deviation
+    /// alongside its volatility, such as the pair returned by
+    /// [`to_glicko_preserving`](#method.to_glicko_preserving).
+    pub fn from_glicko(rating: GlickoRating<F>, volatility: F) -> Glicko2Rating<F> {
+        Glicko2Rating {
+            volatility,
+            ..Glicko2Rating::from(rating)
+        }
+    }
+
+    /// Seeds a `Glicko2Rating` for a player or team with a rating imported from another system,
+    /// such as when migrating from Elo.
+    ///
+    /// `glicko_value` and `initial_deviation` are on the Glicko scale, which was designed so that
+    /// values are comparable to Elo ratings, so an Elo rating can usually be used as the value
+    /// directly. The deviation should reflect how much the imported rating is trusted:
+    /// 350 (the deviation of an unrated player or team) ignores it entirely, while something
+    /// around 100 treats it as fairly well established. A `volatility` of 0.06 is a typical choice.
+    ///
+    /// This is equivalent to calling [`from_glicko`](#method.from_glicko) with a `GlickoRating`.
+    pub fn seed(glicko_value: F, initial_deviation: F, volatility: F) -> Glicko2Rating<F> {
+        Glicko2Rating::from_glicko(
+            GlickoRating {
+                value: glicko_value,
+                deviation: initial_deviation,
+            },
+            volatility,
+        )
+    }
+
+    /// Converts a rating on the Glicko scale to the Glicko2 scale, checking that it is valid.
+    ///
+    /// This is the fallible equivalent of the `From<GlickoRating>` impl, which silently
+    /// propagates NaN. (`TryFrom` can't be implemented, as it conflicts with the `From` impl.)
+    /// Returns an error under the same conditions as [`GlickoRating::try_new`](struct.GlickoRating.html#method.try_new),
+    /// and also if the deviation is zero, as no rating is ever that certain.
+    pub fn try_from_glicko(rating: GlickoRating<F>) -> Result<Glicko2Rating<F>, Glicko2Error> {
+        validate_conversion(rating.value, rating.deviation)?;
+        Ok(Glicko2Rating::from(rating))
+    }
+
+    /// Converts this rating to the Glicko scale, returning the volatility alongside it.
+    ///
+    /// The `From<Glicko2Rating>` impl for `GlickoRating` discards the volatility, so converting back
+    /// loses it. Storing both halves of the returned pair allows the full rating to be restored
+    /// with [`from_glicko`](#method.from_glicko),
+    /// up to floating point rounding in the scale conversion.
+    pub fn to_glicko_preserving(&self) -> (GlickoRating<F>, F) {
+        (GlickoRating::from(*self), self.volatility)
+    }
+
+    /// Constructs a `Glicko2Rating` using the defaults for a new (unrated) player or team.
+    pub fn unrated() -> Glicko2Rating<F> {
+        Glicko2Rating::unrated_with_volatility(cast(DEFAULT_VOLATILITY))
+    }
+
+    /// Constructs a `Glicko2Rating` using the defaults for a new (unrated) player or team,
+    /// but with a starting volatility of `volatility`.
+    ///
+    /// The glicko2 paper notes that the appropriate starting volatility depends on the application;
+    /// [`unrated`](#method.unrated) uses the paper's suggestion of 0.06.
+    pub fn unrated_with_volatility(volatility: F) -> Glicko2Rating<F> {
+        Glicko2Rating {
+            volatility,
+            ..Glicko2Rating::from(GlickoRating::unrated())
+        }
+    }
+
+    /// Compares the values of two ratings, ignoring deviation and volatility.
+    ///
+    /// This is a total order suitable for sorting, such as with `slice::sort_by`.
+    /// A value of NaN is ordered after all other values.
+    pub fn cmp_by_value(&self, other: &Glicko2Rating<F>) -> Ordering {
+        cmp_values(self.value, other.value)
+    }
+
+    /// Returns the lower and upper bounds on the Glicko scale of the interval `z` standard deviations
+    /// either side of the rating value.
+    ///
+    /// This is equivalent to converting the rating to a `GlickoRating` and calling
+    /// [`GlickoRating::confidence_interval`](struct.GlickoRating.html#method.confidence_interval).
+    pub fn glicko_interval(&self, z: F) -> (F, F) {
+        GlickoRating::from(*self).confidence_interval(z)
+    }
+
+    /// Returns whether every field of this rating is within `epsilon` of the same field of `other`.
+    ///
+    /// This is useful for comparing ratings in tests, where exact float equality is too strict.
+    /// NaN fields are never approximately equal to anything.
+    pub fn approx_eq(&self, other: &Glicko2Rating<F>, epsilon: F) -> bool {
+        (self.value - other.value).abs() <= epsilon
+            && (self.deviation - other.deviation).abs() <= epsilon
+            && (self.volatility - other.volatility).abs() <= epsilon
+    }
+
+    /// Returns a copy of this rating with every field forced into a range that is safe to pass to
+    /// [`new_rating`](fn.new_rating.html).
+    ///
+    /// This is intended for ratings from untrusted sources, such as clients of a server, which
+    /// could otherwise produce NaN or nonsensical results. Specifically:
+    ///
+    /// - Any field that is NaN or infinite is replaced by the same field of
+    ///   [`unrated`](#method.unrated).
+    /// - `deviation` is clamped between 0.000001 and the deviation of an unrated rating,
+    ///   because a deviation of zero divides by zero and a deviation above that of an unrated
+    ///   rating claims less is known about the player than about a brand new one.
+    /// - `volatility` is clamped between 0.000001 and 1.0, because a volatility of zero or less
+    ///   has no logarithm, and volatilities far above the suggested starting value of 0.06 cause
+    ///   wild swings in rating.
+    ///
+    /// A rating that is already within these ranges is returned unchanged.
+    pub fn sanitized(self) -> Glicko2Rating<F> {
+        let unrated = Glicko2Rating::unrated();
+        let finite_or = |field: F, default: F| if field.is_finite() { field } else { default };
+        Glicko2Rating {
+            value: finite_or(self.value, unrated.value),
+            deviation: num_traits::clamp(
+                finite_or(self.deviation, unrated.deviation),
+                cast(MIN_DEVIATION),
+                unrated.deviation,
+            ),
+            volatility: num_traits::clamp(
+                finite_or(self.volatility, unrated.volatility),
+                cast(MIN_VOLATILITY),
+                cast(MAX_VOLATILITY),
+            ),
+        }
+    }
+
+    /// Updates this rating in place from a series of results.
+    ///
+    /// This is equivalent to replacing the rating with the result of [`new_rating`](fn.new_rating.html).
+    pub fn update(&mut self, results: &[GameResult<F>], sys_constant: F) {
+        *self = new_rating(*self, results, sys_constant);
+    }
+}
+
+impl<F: Float> GlickoRating<F> {
+    /// Constructs a `GlickoRating` from its fields.
+    ///
+    /// This is a `const fn`, so it can be used to declare known ratings as constants or statics.
+    /// The fields are not checked; see [`try_new`](#method.try_new) for a checked constructor.
+    pub const fn new(value: F, deviation: F) -> GlickoRating<F> {
+        GlickoRating { value, deviation }
+    }
+
+    /// Constructs a `GlickoRating`, checking that the supplied fields are valid.
+    ///
+    /// Returns an error if any field is NaN or infinite, or if `deviation` is negative.
+    pub fn try_new(value: F, deviation: F) -> Result<GlickoRating<F>, Glicko2Error> {
+        validate_value_and_deviation(value, deviation)?;
+        Ok(GlickoRating { value, deviation })
+    }
+
+    /// Converts a rating on the Glicko2 scale to the Glicko scale, checking that it is valid.
+    ///
+    /// This is the fallible equivalent of the `From<Glicko2Rating>` impl, which silently
+    /// propagates NaN. (`TryFrom` can't be implemented, as it conflicts with the `From` impl.)
+    /// Returns an error under the same conditions as [`Glicko2Rating::try_new`](struct.Glicko2Rating.html#method.try_new),
+    /// and also if the deviation is zero, as no rating is ever that certain.
+    pub fn try_from_glicko2(rating: Glicko2Rating<F>) -> Result<GlickoRating<F>, Glicko2Error> {
+        Glicko2Rating::try_new(rating.value, rating.deviation, rating.volatility)?;
+        validate_conversion(rating.value, rating.deviation)?;
+        Ok(GlickoRating::from(rating))
+    }
+
+    /// Returns whether this rating satisfies the invariants of the crate: both fields are finite,
+    /// and the deviation is zero or positive.
+    ///
+    /// These are exactly the conditions checked by [`try_new`](#method.try_new).
+    /// See [`Glicko2Rating::is_valid`](struct.Glicko2Rating.html#method.is_valid).
+    pub fn is_valid(&self) -> bool {
+        validate_value_and_deviation(self.value, self.deviation).is_ok()
+    }
+
+    /// Constructs a `GlickoRating` using the defaults for a new (unrated) player or team.
+    pub fn unrated() -> GlickoRating<F> {
+        GlickoRating {
+            value: cast(GLICKO_BASE),
+            deviation: cast(UNRATED_DEVIATION),
+        }
+    }
+
+    /// Returns the lower and upper bounds of the interval `z` standard deviations
+    /// either side of the rating value.
+    ///
+    /// For example, a `z` of 1.96 gives a 95% confidence interval for the true skill
+    /// of the player or team.
+    pub fn confidence_interval(&self, z: F) -> (F, F) {
+        (self.value - z * self.deviation, self.value + z * self.deviation)
+    }
+
+    /// Returns a conservative estimate of the skill of the player or team,
+    /// the rating value minus `k` times the deviation.
+    ///
+    /// Sorting a leaderboard by this, rather than by value, prevents new players or teams with very
+    /// uncertain ratings from immediately topping it. A `k` of 2 approximates the lower bound of
+    /// a 95% confidence interval; TrueSkill popularized displaying a `k` of 3.
+    pub fn conservative(&self, k: F) -> F {
+        self.value - k * self.deviation
+    }
+
+    /// Returns how reliable the rating is, from 0.0 for an unrated player or team towards 1.0
+    /// as the deviation shrinks, such as for displaying a confidence bar.
+    ///
+    /// This is `1 - (deviation / 350)²`: the fraction of the uncertainty (variance) of an unrated
+    /// rating that has been eliminated. A deviation of 50 gives about 0.98, and 110, a common
+    /// threshold for provisional ratings, gives about 0.9. Deviations above 350 give 0.0.
+    pub fn confidence(&self) -> F {
+        let relative_deviation = self.deviation / cast(UNRATED_DEVIATION);
+        (F::one() - relative_deviation * relative_deviation).max(F::zero())
+    }
+
+    /// Estimates the fraction of players or teams that this one is stronger than, assuming that the
+    /// rating values of the population are normally distributed with mean `mean` and standard
+    /// deviation `std_dev`, both on the Glicko scale.
+    ///
+    /// This is the normal cumulative distribution function evaluated at the rating value, so a
+    /// value of 1800 in a population of 1500 ± 300 gives about 0.84, for a message like "you're
+    /// stronger than 84% of players". Unlike [`percentile`](fn.percentile.html), the actual ratings
+    /// of the population aren't needed, but the result is only as good as the assumption that they
+    /// are normally distributed, which real populations often aren't, particularly at the extremes.
+    /// The deviation of this rating is ignored. The result is accurate to within about 0.0000002.
+    pub fn population_percentile(&self, mean: F, std_dev: F) -> F {
+        normal_cdf((self.value - mean) / std_dev)
+    }
+
+    /// Calculates the expected score of this player or team in a game against `opponent`.
+    ///
+    /// This converts both ratings to the Glicko2 scale and calls
+    /// [`win_probability`](fn.win_probability.html), so the result is identical.
+    pub fn expected_score(&self, opponent: &GlickoRating<F>) -> F {
+        win_probability(Glicko2Rating::from(*self), Glicko2Rating::from(*opponent))
+    }
+
+    /// Returns whether every field of this rating is within `epsilon` of the same field of `other`.
+    ///
+    /// This is useful for comparing ratings in tests, where exact float equality is too strict.
+    /// NaN fields are never approximately equal to anything.
+    pub fn approx_eq(&self, other: &GlickoRating<F>, epsilon: F) -> bool {
+        (self.value - other.value).abs() <= epsilon
+            && (self.deviation - other.deviation).abs() <= epsilon
+    }
+
+    /// Returns whether the rating is still provisional, that is, whether its deviation exceeds `threshold`.
+    ///
+    /// A common choice for `threshold` is [`PROVISIONAL_DEVIATION`](constant.PROVISIONAL_DEVIATION.html).
+    pub fn is_provisional(&self, threshold: F) -> bool {
+        self.deviation > threshold
+    }
+
+    /// Compares the values of two ratings, ignoring deviation.
+    ///
+    /// This is a total order suitable for sorting, such as with `slice::sort_by`.
+    /// A value of NaN is ordered after all other values.
+    pub fn cmp_by_value(&self, other: &GlickoRating<F>) -> Ordering {
+        cmp_values(self.value, other.value)
+    }
+}
+
+/// Formats the rating as `value ± deviation`, such as `1500 ± 350`.
+///
+/// A precision supplied to the formatter, such as in `{:.0}`, is applied to both fields.
+impl<F: Float + fmt::Display> fmt::Display for GlickoRating<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(
+                f,
+                "{:.*} ± {:.*}",
+                precision, self.value, precision, self.deviation
+            ),
+            None => write!(f, "{} ± {}", self.value, self.deviation),
+        }
+    }
+}
+
+/// Formats the rating as `value ± deviation, volatility volatility`, followed by the rating
+/// on the Glicko scale in parentheses, such as `0 ± 2.0147, volatility 0.06 (1500 ± 350)`.
+///
+/// A precision supplied to the formatter, such as in `{:.2}`, is applied to all fields.
+impl<F: Float + fmt::Display> fmt::Display for Glicko2Rating<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let glicko = GlickoRating::from(*self);
+        match f.precision() {
+            Some(precision) => write!(
+                f,
+                "{:.*} ± {:.*}, volatility {:.*} ({:.*})",
+                precision,
+                self.value,
+                precision,
+                self.deviation,
+                precision,
+                self.volatility,
+                precision,
+                glicko
+            ),
+            None => write!(
+                f,
+                "{} ± {}, volatility {} ({})",
+                self.value, self.deviation, self.volatility, glicko
+            ),
+        }
+    }
+}
+
+/// Parses a rating of the form `value/deviation`, such as `1500/350`.
+///
+/// Whitespace around each field is ignored, and each field may be written in scientific notation.
+impl<F: Float + FromStr> FromStr for GlickoRating<F> {
+    type Err = ParseRatingError;
+
+    fn from_str(s: &str) -> Result<GlickoRating<F>, ParseRatingError> {
+        let mut fields = [F::zero(); 2];
+        parse_fields(s, &["value", "deviation"], &mut fields)?;
+        Ok(GlickoRating {
+            value: fields[0],
+            deviation: fields[1],
+        })
+    }
+}
+
+/// Parses a rating of the form `value/deviation/volatility`, such as `0/2.0148/0.06`.
+///
+/// Note that all fields are on the Glicko2 scale. Whitespace around each field is ignored,
+/// and each field may be written in scientific notation.
+impl<F: Float + FromStr> FromStr for Glicko2Rating<F> {
+    type Err = ParseRatingError;
+
+    fn from_str(s: &str) -> Result<Glicko2Rating<F>, ParseRatingError> {
+        let mut fields = [F::zero(); 3];
+        parse_fields(s, &["value", "deviation", "volatility"], &mut fields)?;
+        Ok(Glicko2Rating {
+            value: fields[0],
+            deviation: fields[1],
+            volatility: fields[2],
+        })
+    }
+}
+
+fn parse_fields<F: FromStr>(
+    s: &str,
+    names: &[&'static str],
+    fields: &mut [F],
+) -> Result<(), ParseRatingError> {
+    let found = s.split('/').count();
+    if found != names.len() {
+        return Err(ParseRatingError::WrongFieldCount {
+            expected: names.len(),
+            found,
+        });
+    }
+    for ((field, name), text) in fields.iter_mut().zip(names).zip(s.split('/')) {
+        *field = text
+            .trim()
+            .parse()
+            .map_err(|_| ParseRatingError::InvalidNumber { field: name })?;
+    }
+    Ok(())
+}
+
+impl<F: Float> Default for Glicko2Rating<F> {
+    fn default() -> Glicko2Rating<F> {
+        Glicko2Rating::unrated()
+    }
+}
+
+impl<F: Float> Default for GlickoRating<F> {
+    fn default() -> GlickoRating<F> {
+        GlickoRating::unrated()
+    }
+}
+
+/// Serializes a rating to a JSON string, such as `{"value":0.0,"deviation":2.0,"volatility":0.06}`.
+///
+/// This is a convenience wrapper around `serde_json::to_string`.
+#[cfg(feature = "json")]
+pub fn to_json<F: Float + Serialize>(rating: &Glicko2Rating<F>) -> String {
+    serde_json::to_string(rating).expect("serializing a rating to JSON can't fail")
+}
+
+/// Deserializes a rating from a JSON string, as produced by [`to_json`](fn.to_json.html).
+///
+/// This is a convenience wrapper around `serde_json::from_str`.
+#[cfg(feature = "json")]
+pub fn from_json<F: Float + DeserializeOwned>(
+    json: &str,
+) -> Result<Glicko2Rating<F>, serde_json::Error> {
+    serde_json::from_str(json)
+}
+
+fn validate_value_and_deviation<F: Float>(value: F, deviation: F) -> Result<(), Glicko2Error> {
+    if !value.is_finite() {
+        return Err(Glicko2Error::NonFinite { field: "value" });
+    }
+    if !deviation.is_finite() {
+        return Err(Glicko2Error::NonFinite { field: "deviation" });
+    }
+    if deviation < F::zero() {
+        return Err(Glicko2Error::InvalidDeviation);
+    }
+    Ok(())
+}
+
+fn validate_tolerance<F: Float>(tolerance: F) -> Result<(), Glicko2Error> {
+    if tolerance.is_nan() || tolerance <= F::zero() {
+        return Err(Glicko2Error::InvalidTolerance);
+    }
+    Ok(())
+}
+
+// Stricter than `validate_value_and_deviation`, as the fallible conversions between scales
+// also reject a deviation of zero.
+fn validate_conversion<F: Float>(value: F, deviation: F) -> Result<(), Glicko2Error> {
+    validate_value_and_deviation(value, deviation)?;
+    if deviation == F::zero() {
+        return Err(Glicko2Error::InvalidDeviation);
+    }
+    Ok(())
+}
+
+fn validate_result<F: Float>(result: &GameResult<F>) -> Result<(), Glicko2Error> {
+    if !result.opponent_rating_value.is_finite() {
+        return Err(Glicko2Error::NonFinite {
+            field: "opponent value",
+        });
+    }
+    if !result.opponent_rating_deviation.is_finite() {
+        return Err(Glicko2Error::NonFinite {
+            field: "opponent deviation",
+        });
+    }
+    if result.opponent_rating_deviation < F::zero() {
+        return Err(Glicko2Error::InvalidDeviation);
+    }
+    if !result.weight.is_finite() {
+        return Err(Glicko2Error::NonFinite { field: "weight" });
+    }
+    Score::custom(result.score)?;
+    Ok(())
+}
+
+fn cmp_values<F: Float>(value: F, other_value: F) -> Ordering {
+    value
+        .partial_cmp(&other_value)
+        .unwrap_or_else(|| value.is_nan().cmp(&other_value.is_nan()))
+}
+
+// The cumulative distribution function of the standard normal distribution, calculated with the
+// approximation of erf from Abramowitz and Stegun (7.1.26), which has a maximum error of 1.5e-7.
+fn normal_cdf<F: Float>(z: F) -> F {
+    let x = z.abs() / cast::<F>(2.0).sqrt();
+    let t = (F::one() + cast::<F>(0.3275911) * x).recip();
+    let polynomial = [
+        1.061405429,
+        -1.453152027,
+        1.421413741,
+        -0.284496736,
+        0.254829592,
+    ]
+    .iter()
+    .fold(F::zero(), |acc, &coefficient| (acc + cast(coefficient)) * t);
+    let erf = F::one() - polynomial * (-x * x).exp();
+    let half: F = cast(0.5);
+    if z < F::zero() {
+        half * (F::one() - erf)
+    } else {
+        half * (F::one() + erf)
+    }
+}
+
+// Converts one of the constants used throughout the calculations to the float type in use.
+// This can't fail for any of the float types that implement `Float`.
+fn cast<F: Float>(constant: f64) -> F {
+    <F as NumCast>::from(constant).unwrap()
+}
+
+// The rest is best read with a copy of the glicko2 example PDF;
+// I've tried to keep naming somewhat consistent
+// http://www.glicko.net/glicko/glicko2.pdf
+// One difference is that what is referred to in the pdf as 'player'
+// I am referring to as a `rating`, and what is referred to as `rating`
+// I am referring to as a `value`. I think that these changes make
+// the API more clear, hopefully it's not too confusing.
+
+/// Accumulates the results of a single player or team over a rating period,
+/// for servers that receive games one at a time.
+///
+/// Once the rating period is over, call [`finalize`](#method.finalize) to calculate the new rating.
+#[derive(Clone, Debug, Default)]
+pub struct RatingPeriod<F = f64> {
+    results: Vec<GameResult<F>>,
+}
+
+impl<F: Float> RatingPeriod<F> {
+    /// Constructs an empty `RatingPeriod`.
+    pub fn new() -> RatingPeriod<F> {
+        RatingPeriod {
+            results: Vec::new(),
+        }
+    }
+
+    /// Records an arbitrary game result.
+    pub fn record(&mut self, result: GameResult<F>) -> &mut RatingPeriod<F> {
+        self.results.push(result);
+        self
+    }
+
+    /// Records a win over a player or team with rating `opponent_rating`.
+    pub fn record_win<T: Into<Glicko2Rating<F>>>(
+        &mut self,
+        opponent_rating: T,
+    ) -> &mut RatingPeriod<F> {
+        self.record(GameResult::win(opponent_rating))
+    }
+
+    /// Records a loss to a player or team with rating `opponent_rating`.
+    pub fn record_loss<T: Into<Glicko2Rating<F>>>(
+        &mut self,
+        opponent_rating: T,
+    ) -> &mut RatingPeriod<F> {
+        self.record(GameResult::loss(opponent_rating))
+    }
+
+    /// Records a draw with a player or team with rating `opponent_rating`.
+    pub fn record_draw<T: Into<Glicko2Rating<F>>>(
+        &mut self,
+        opponent_rating: T,
+    ) -> &mut RatingPeriod<F> {
+        self.record(GameResult::draw(opponent_rating))
+    }
+
+    /// Returns the results recorded so far.
+    pub fn results(&self) -> &[GameResult<F>] {
+        &self.results
+    }
+
+    /// Ends the rating period, calculating the new rating of the player or team from `prior_rating`
+    /// and the recorded results with [`new_rating`](fn.new_rating.html).
+    pub fn finalize(self, prior_rating: Glicko2Rating<F>, sys_constant: F) -> Glicko2Rating<F> {
+        new_rating(prior_rating, &self.results, sys_constant)
+    }
+}
+
+/// A fixed-capacity equivalent of [`RatingPeriod`](struct.RatingPeriod.html),
+/// which stores up to `N` results inline rather than allocating.
+///
+/// This is intended for embedded environments where allocation is forbidden.
+/// Recording a result when `N` results have already been recorded fails,
+/// returning the result that could not be recorded.
+#[cfg(feature = "heapless")]
+#[derive(Clone, Debug, Default)]
+pub struct FixedRatingPeriod<F, const N: usize> {
+    results: heapless::Vec<GameResult<F>, N>,
+}
+
+#[cfg(feature = "heapless")]
+impl<F: Float, const N: usize> FixedRatingPeriod<F, N> {
+    /// Constructs an empty `FixedRatingPeriod`.
+    pub fn new() -> FixedRatingPeriod<F, N> {
+        FixedRatingPeriod {
+            results: heapless::Vec::new(),
+        }
+    }
+
+    /// Records an arbitrary game result.
+    pub fn record(
+        &mut self,
+        result: GameResult<F>,
+    ) -> Result<&mut FixedRatingPeriod<F, N>, GameResult<F>> {
+        self.results.push(result)?;
+        Ok(self)
+    }
+
+    /// Records a win over a player or team with rating `opponent_rating`.
+    pub fn record_win<T: Into<Glicko2Rating<F>>>(
+        &mut self,
+        opponent_rating: T,
+    ) -> Result<&mut FixedRatingPeriod<F, N>, GameResult<F>> {
+        self.record(GameResult::win(opponent_rating))
+    }
+
+    /// Records a loss to a player or team with rating `opponent_rating`.
+    pub fn record_loss<T: Into<Glicko2Rating<F>>>(
+        &mut self,
+        opponent_rating: T,
+    ) -> Result<&mut FixedRatingPeriod<F, N>, GameResult<F>> {
+        self.record(GameResult::loss(opponent_rating))
+    }
+
+    /// Records a draw with a player or team with rating `opponent_rating`.
+    pub fn record_draw<T: Into<Glicko2Rating<F>>>(
+        &mut self,
+        opponent_rating: T,
+    ) -> Result<&mut FixedRatingPeriod<F, N>, GameResult<F>> {
+        self.record(GameResult::draw(opponent_rating))
+    }
+
+    /// Returns the results recorded so far.
+    pub fn results(&self) -> &[GameResult<F>] {
+        &self.results
+    }
+
+    /// Ends the rating period, calculating the new rating of the player or team from `prior_rating`
+    /// and the recorded results with [`new_rating`](fn.new_rating.html).
+    pub fn finalize(self, prior_rating: Glicko2Rating<F>, sys_constant: F) -> Glicko2Rating<F> {
+        new_rating(prior_rating, &self.results, sys_constant)
+    }
+}
+
+/// Calculates the expected score of a player or team with rating `player`
+/// in a game against a player or team with rating `opponent`.
+///
+/// The expected score is the probability of winning plus half the probability of drawing,
+/// so it will always be in the range [0.0, 1.0]. The deviations of both ratings are taken into account,
+/// with more uncertain ratings pulling the expected score towards 0.5.
+///
+/// This deliberately differs from the expected score `E` used by [`new_rating`](fn.new_rating.html),
+/// which is [`math::e`](math/fn.e.html) with the deviation of the opponent alone, as in the
+/// glicko2 paper. Here `math::e` is given the combined deviation `sqrt(φ² + φj²)` of both ratings
+/// instead, so that the prediction is symmetric: the expected scores of the two sides always sum
+/// to 1. The two agree when the deviation of `player` is 0, and otherwise this is closer to 0.5.
+pub fn win_probability<F: Float>(player: Glicko2Rating<F>, opponent: Glicko2Rating<F>) -> F {
+    let combined_deviation =
+        ((player.deviation * player.deviation) + (opponent.deviation * opponent.deviation)).sqrt();
+    e(player.value, opponent.value, combined_deviation)
+}
+
+/// Calculates the expected score of a player or team with rating `player` in a game against
+/// an opponent whose rating value, `opponent_value`, is known but whose deviation isn't,
+/// such as an opponent with a rating imported from another system. Both are on the Glicko scale.
+///
+/// The opponent's rating is assumed to be well established, with a deviation of 0,
+/// so only the deviation of `player` pulls the expected score towards 0.5. This overestimates
+/// the confidence of the prediction if the opponent's rating is in fact uncertain.
+/// See [`win_probability`](fn.win_probability.html) for details.
+pub fn win_probability_point<F: Float>(player: GlickoRating<F>, opponent_value: F) -> F {
+    let opponent = GlickoRating {
+        value: opponent_value,
+        deviation: F::zero(),
+    };
+    win_probability(player.into(), opponent.into())
+}
+
+/// Calculates the rating value, on the Glicko scale, that a player or team needs to have an
+/// expected score of `target_probability` in a game against a player or team with rating `opponent`,
+/// such as to answer "what rating do I need to have a 75% chance against this player?".
+///
+/// This inverts the `E` function of the glicko2 paper, which takes the deviation of the opponent
+/// into account but not that of the player or team, as it isn't known. The expected score
+/// calculated by [`win_probability`](fn.win_probability.html) for a rating with this value and a
+/// deviation of 0 is `target_probability` (up to floating point rounding); any larger deviation
+/// pulls it towards 0.5.
+///
+/// A `target_probability` of exactly 0.0 or 1.0 can't be reached by any finite rating,
+/// so negative or positive infinity is returned respectively. Probabilities outside of
+/// [0.0, 1.0] give NaN.
+pub fn rating_for_win_probability<F: Float>(
+    opponent: Glicko2Rating<F>,
+    target_probability: F,
+) -> F {
+    let log_odds = (target_probability / (F::one() - target_probability)).ln();
+    let value = opponent.value + log_odds / g(opponent.deviation);
+    let params = ScaleParams::<F>::standard();
+    value * params.scale + params.base
+}
+
+/// Calculates how evenly matched a game between two players or teams would be.
+///
+/// The match quality is 1.0 when the expected score of the game is exactly 0.5,
+/// falling linearly towards 0.0 as the game becomes more lopsided. That is, it is
+/// `1 - 2 * |E - 0.5|`, where `E` is the expected score as calculated by [`win_probability`](fn.win_probability.html).
+pub fn match_quality<F: Float>(a: Glicko2Rating<F>, b: Glicko2Rating<F>) -> F {
+    let half: F = cast(0.5);
+    F::one() - cast::<F>(2.0) * (win_probability(a, b) - half).abs()
+}
+
+/// Pairs up players or teams waiting for a game, such as in a matchmaking queue,
+/// returning the indices into `ratings` of each pair.
+///
+/// Pairs are chosen greedily: the pair with the highest [`match_quality`](fn.match_quality.html)
+/// is paired first, then the best pair among the remaining players or teams, and so on.
+/// This usually finds a pairing with a total match quality close to the best possible, but isn't
+/// guaranteed to find the best. Ties are broken by index, so the same ratings always produce
+/// the same pairs. Within each pair the smaller index is first, and if there is an odd number
+/// of ratings, one is left unpaired.
+///
+/// Every possible pair is considered, so this takes `O(n² log n)` time for `n` ratings.
+pub fn pair_pool<F: Float>(ratings: &[Glicko2Rating<F>]) -> Vec<(usize, usize)> {
+    let mut candidates = Vec::new();
+    for (i, &a) in ratings.iter().enumerate() {
+        for (j, &b) in ratings.iter().enumerate().skip(i + 1) {
+            candidates.push((match_quality(a, b), i, j));
+        }
+    }
+    candidates.sort_by(|&(quality, i, j), &(other_quality, other_i, other_j)| {
+        cmp_values(other_quality, quality).then((i, j).cmp(&(other_i, other_j)))
+    });
+
+    let mut paired = vec![false; ratings.len()];
+    let mut pairs = Vec::with_capacity(ratings.len() / 2);
+    for (_, i, j) in candidates {
+        if !paired[i] && !paired[j] {
+            paired[i] = true;
+            paired[j] = true;
+            pairs.push((i, j));
+        }
+    }
+    pairs
+}
+
+/// Calculates the expected score of every player or team in `ratings` against every other,
+/// such as for seeding a tournament.
+///
+/// Entry `[i][j]` of the returned matrix is the [`win_probability`](fn.win_probability.html) of
+/// `ratings[i]` against `ratings[j]`, so `[i][j] + [j][i]` is always 1 (up to floating point
+/// rounding), and every entry on the diagonal is 0.5.
+pub fn expected_score_matrix<F: Float>(ratings: &[Glicko2Rating<F>]) -> Vec<Vec<F>> {
+    ratings
+        .iter()
+        .map(|&player| {
+            ratings
+                .iter()
+                .map(|&opponent| win_probability(player, opponent))
+                .collect()
+        })
+        .collect()
+}
+
+/// Calculates the probabilities of a player or team with rating `a` winning, drawing
+/// and losing a game against a player or team with rating `b`, in that order.
+///
+/// This is [`outcome_probabilities_with_model`](fn.outcome_probabilities_with_model.html) with
+/// the default [`DrawModel`](enum.DrawModel.html), `DrawModel::NoDraws`, so the probability of a
+/// draw is always zero and the probabilities are `(p, 0, 1 - p)`, where `p` is the
+/// [`win_probability`](fn.win_probability.html). `outcome_probabilities_with_model` is the
+/// general form, for games where draws are possible;
+/// [`outcome_probabilities_with_draw_rate`](fn.outcome_probabilities_with_draw_rate.html) is a
+/// shorthand for it with `DrawModel::DrawRate`.
+pub fn outcome_probabilities<F: Float>(a: Glicko2Rating<F>, b: Glicko2Rating<F>) -> (F, F, F) {
+    outcome_probabilities_with_model(a, b, DrawModel::default())
+}
+
+/// A model of how likely games are to be drawn, used by
+/// [`outcome_probabilities_with_model`](fn.outcome_probabilities_with_model.html)
+/// to split the expected score of a game into the probabilities of winning, drawing and losing.
+///
+/// Glicko2 only models the expected score `p` of a game, as calculated by
+/// [`win_probability`](fn.win_probability.html), which counts a draw as half a win.
+/// Which model fits best depends on the game, so the parameters should be fitted to,
+/// or at least checked against, real results.
+///
+/// More models may be added in the future, so matches must include a wildcard arm.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum DrawModel<F = f64> {
+    /// Games are never drawn, so the probabilities are `(p, 0, 1 - p)`.
+    /// This is the default, and suits games without draws.
+    #[default]
+    NoDraws,
+    /// The probability of a draw is the contained draw rate, the probability of a draw between
+    /// evenly matched players, multiplied by the [`match_quality`](fn.match_quality.html)
+    /// of the game.
+    ///
+    /// The remaining probability is split between winning and losing such that the expected score
+    /// is still exactly `p`. See
+    /// [`outcome_probabilities_with_draw_rate`](fn.outcome_probabilities_with_draw_rate.html).
+    DrawRate(F),
+    /// Davidson's model, with the contained draw parameter `ν`, which must be zero or positive.
+    ///
+    /// The odds of winning against losing are kept at `p / (1 - p)`, as in the Bradley-Terry model
+    /// underlying the expected score, while the odds of drawing against losing are
+    /// `ν * sqrt(p / (1 - p))`. That is, with `s = sqrt(p * (1 - p))`, the probabilities are
+    /// `p / (1 + ν s)`, `ν s / (1 + ν s)` and `(1 - p) / (1 + ν s)`.
+    ///
+    /// The probability of a draw between evenly matched players is `ν / (2 + ν)`, so a draw
+    /// rate of `d` between evenly matched players is modelled by `ν = 2d / (1 - d)`:
+    /// a `ν` of 0.0 gives no draws, 0.5 gives 20% draws, and 2.0 gives 50% draws.
+    /// Unlike `DrawRate`, draws pull the expected score of a lopsided game slightly towards 0.5.
+    Davidson(F),
+}
+
+/// Calculates the probabilities of a player or team with rating `a` winning, drawing
+/// and losing a game against a player or team with rating `b`, in that order,
+/// using `draw_model` to model draws.
+///
+/// The probabilities always sum to 1 (up to floating point rounding).
+/// See [`DrawModel`](enum.DrawModel.html) for the available models.
+pub fn outcome_probabilities_with_model<F: Float>(
+    a: Glicko2Rating<F>,
+    b: Glicko2Rating<F>,
+    draw_model: DrawModel<F>,
+) -> (F, F, F) {
+    match draw_model {
+        DrawModel::NoDraws => outcome_probabilities_with_draw_rate(a, b, F::zero()),
+        DrawModel::DrawRate(draw_rate) => outcome_probabilities_with_draw_rate(a, b, draw_rate),
+        DrawModel::Davidson(draw_parameter) => {
+            let p = win_probability(a, b);
+            let draw_odds = draw_parameter * (p * (F::one() - p)).sqrt();
+            let total = F::one() + draw_odds;
+            (p / total, draw_odds / total, (F::one() - p) / total)
+        }
+    }
+}
+
+/// Calculates the probabilities of a player or team with rating `a` winning, drawing
+/// and losing a game against a player or team with rating `b`, in that order.
+///
+/// This is a shorthand for [`outcome_probabilities_with_model`](fn.outcome_probabilities_with_model.html)
+/// with `DrawModel::DrawRate(draw_rate)`, and with a `draw_rate` of 0.0 it is the same as
+/// [`outcome_probabilities`](fn.outcome_probabilities.html).
+///
+/// Glicko2 only models the expected score of a game, so a model is needed to split it into
+/// three outcomes. Here `draw_rate` is the probability of a draw between evenly matched players,
+/// and the probability of a draw is `draw_rate` multiplied by the [`match_quality`](fn.match_quality.html)
+/// of the game, so draws become rarer as the game becomes more lopsided. The remaining probability is
+/// split between winning and losing such that the expected score is unchanged from
+/// [`win_probability`](fn.win_probability.html).
+///
+/// A `draw_rate` of 0.0 suits games without draws. Reasonable values for other games can be found by
+/// measuring how often draws occur between players of similar rating. `draw_rate` is clamped
+/// to the range [0.0, 1.0].
+pub fn outcome_probabilities_with_draw_rate<F: Float>(
+    a: Glicko2Rating<F>,
+    b: Glicko2Rating<F>,
+    draw_rate: F,
+) -> (F, F, F) {
+    let expected_score = win_probability(a, b);
+    let draw = num_traits::clamp(draw_rate, F::zero(), F::one()) * match_quality(a, b);
+    let half_draw = draw / cast(2.0);
+    (
+        expected_score - half_draw,
+        draw,
+        F::one() - expected_score - half_draw,
+    )
+}
+
+/// Estimates the number of games that `rating` represents: how many evenly matched games,
+/// played in a single rating period, would reduce the deviation of an unrated player or team
+/// to that of `rating`.
+///
+/// This is a rough, human-friendly stand-in for the deviation, such as for telling players that
+/// their rating is "worth about 30 games". It assumes that every game is against an opponent of
+/// exactly equal rating with a deviation of 0. Each such game then contributes the same amount of
+/// information, regardless of its result, as the glicko2 system doesn't distinguish between
+/// draws and decisive results when updating the deviation, so the draw rate doesn't matter.
+/// Growth in deviation between rating periods is ignored, so players who have played over many
+/// rating periods will usually have played more games than this suggests.
+///
+/// Returns 0.0 for deviations of 350 (that of an unrated player or team) or more,
+/// and infinity for a deviation of 0.
+pub fn effective_games<F: Float>(rating: &GlickoRating<F>) -> F {
+    let params = ScaleParams::<F>::standard();
+    let deviation = rating.deviation / params.scale;
+    let unrated_deviation = cast::<F>(UNRATED_DEVIATION) / params.scale;
+    // An evenly matched game against a certain opponent has g = 1 and E = 0.5,
+    // so it adds g² * E * (1 - E) = 1/4 to the reciprocal of the variance.
+    let information =
+        (deviation * deviation).recip() - (unrated_deviation * unrated_deviation).recip();
+    (cast::<F>(4.0) * information).max(F::zero())
+}
+
+/// Estimates how many games a player or team with rating `prior_rating` needs to play
+/// for their deviation to drop to `target_deviation`, which is on the Glicko2 scale.
+///
+/// This is estimated by simulating rating periods with [`new_rating`](fn.new_rating.html),
+/// assuming that:
+///
+/// - the player or team plays exactly one game each rating period,
+/// - every game is against an opponent with rating `assumed_opponent`,
+/// - and every game is a draw, so that the rating value stays close to that of the opponent.
+///
+/// Real players rarely match these assumptions, so the result should be treated as a rough guide,
+/// such as for a "your rating will be established in about N games" message.
+///
+/// Returns `Some(0)` if the deviation is already at or below the target. Returns `None`
+/// if the target can never be reached, which happens when the growth in deviation each rating
+/// period (driven by the volatility) outweighs the information gained from a single game.
+pub fn games_to_deviation<F: Float>(
+    prior_rating: Glicko2Rating<F>,
+    target_deviation: F,
+    assumed_opponent: Glicko2Rating<F>,
+    sys_constant: F,
+) -> Option<usize> {
+    let results = [GameResult::draw(assumed_opponent)];
+    let mut rating = prior_rating;
+    let mut games = 0;
+    while rating.deviation > target_deviation {
+        let next_rating = new_rating(rating, &results, sys_constant);
+        // Once the deviation stops meaningfully decreasing, it has settled above the target.
+        if next_rating.deviation.is_nan()
+            || rating.deviation - next_rating.deviation < cast(CONVERGENCE_TOLERANCE)
+        {
+            return None;
+        }
+        rating = next_rating;
+        games += 1;
+    }
+    Some(games)
+}
+
+/// Calculates the average rating of the opponents faced in a series of results,
+/// such as for explaining a rating change with "you faced strong opposition".
+///
+/// Each opponent is weighted as in the glicko2 calculation, by the weight of the game times
+/// `g(φj)` (see [`math::g`](math/fn.g.html)), so opponents with uncertain ratings count for less.
+/// The value is the weighted mean of the opponent values, and the deviation is the square root of
+/// the weighted mean of the opponent variances (the squares of their deviations).
+///
+/// Returns `None` if `results` is empty or every result has a weight of 0.0.
+pub fn average_opponent<F: Float>(results: &[GameResult<F>]) -> Option<GlickoRating<F>> {
+    let (total_weight, value_sum, variance_sum) = results.iter().fold(
+        (F::zero(), F::zero(), F::zero()),
+        |(total, value, variance), result| {
+            let deviation = result.opponent_rating_deviation;
+            let weight = result.weight * g(deviation);
+            (
+                total + weight,
+                value + weight * result.opponent_rating_value,
+                variance + weight * deviation * deviation,
+            )
+        },
+    );
+    if total_weight <= F::zero() {
+        return None;
+    }
+    Some(GlickoRating::from(Glicko2Rating {
+        value: value_sum / total_weight,
+        deviation: (variance_sum / total_weight).sqrt(),
+        volatility: cast(DEFAULT_VOLATILITY),
+    }))
+}
+
+/// Calculates the performance rating of a series of results: the rating at which the total
+/// expected score of the games equals the total actual score.
+///
+/// This is the rating a player or team "played at" over the games, as commonly shown
+/// in tournament reports. The value is found by bisection on the Glicko2 scale, using the
+/// same expected score as [`new_rating`](fn.new_rating.html) (including the weights of results).
+/// The deviation is the square root of the estimated variance of a rating based only on
+/// the results at that value (see [`compute_v`](fn.compute_v.html)), so more games against
+/// more certain opponents give a more certain performance rating.
+///
+/// Returns `None` if `results` is empty or has no weight, or if every game was won or every game
+/// was lost, in which case the performance rating is unbounded. `None` is also returned for
+/// degenerate results, such as those with infinite opponent ratings, for which no finite
+/// performance rating can be found.
+pub fn performance_rating<F: Float>(results: &[GameResult<F>]) -> Option<GlickoRating<F>> {
+    let total_weight = results.iter().fold(F::zero(), |acc, result| acc + result.weight);
+    let total_score = results
+        .iter()
+        .fold(F::zero(), |acc, result| acc + result.weight * result.score);
+    if !(total_score > F::zero() && total_score < total_weight) {
+        return None;
+    }
+    let surplus = |value: F| {
+        results.iter().fold(F::zero(), |acc, result| {
+            acc + result.weight
+                * e(value, result.opponent_rating_value, result.opponent_rating_deviation)
+        }) - total_score
+    };
+
+    // The total expected score increases with the value, so widen the bracket until it
+    // contains the root, then bisect. Degenerate inputs can prevent the bracket from ever
+    // being found, so give up after a while, or once the bracket is no longer finite.
+    let mut low = results
+        .iter()
+        .fold(F::infinity(), |acc, result| acc.min(result.opponent_rating_value));
+    let mut high = results
+        .iter()
+        .fold(F::neg_infinity(), |acc, result| acc.max(result.opponent_rating_value));
+    let mut step = F::one();
+    let mut bracket_iterations = 0;
+    while surplus(low) > F::zero() {
+        if bracket_iterations >= MAX_ITERATIONS || !low.is_finite() {
+            return None;
+        }
+        low = low - step;
+        step = step * cast(2.0);
+        bracket_iterations += 1;
+    }
+    step = F::one();
+    bracket_iterations = 0;
+    while surplus(high) < F::zero() {
+        if bracket_iterations >= MAX_ITERATIONS || !high.is_finite() {
+            return None;
+        }
+        high = high + step;
+        step = step * cast(2.0);
+        bracket_iterations += 1;
+    }
+    if !(low.is_finite() && high.is_finite()) {
+        return None;
+    }
+    let mut iterations = 0;
+    while high - low > cast(CONVERGENCE_TOLERANCE) && iterations < MAX_ITERATIONS {
+        let mid = (low + high) / cast(2.0);
+        if surplus(mid) < F::zero() {
+            low = mid;
+        } else {
+            high = mid;
+        }
+        iterations += 1;
+    }
+
+    let performance = Glicko2Rating {
+        value: (low + high) / cast(2.0),
+        ..Glicko2Rating::unrated()
+    };
+    Some(GlickoRating::from(Glicko2Rating {
+        deviation: compute_v(performance, results).sqrt(),
+        ..performance
+    }))
+}
+
+/// Calculates the log loss of a prediction, where `predicted` is the predicted expected score
+/// of a game, such as from [`win_probability`](fn.win_probability.html), and `actual_score` is
+/// the score of the game.
+///
+/// Lower is better. This is useful for measuring the predictive accuracy of the system,
+/// such as when choosing a system constant as Glickman recommends. To keep the result finite,
+/// `predicted` is clamped between `F::epsilon()` and `1 - F::epsilon()`.
+pub fn log_loss<F: Float>(predicted: F, actual_score: F) -> F {
+    let predicted = num_traits::clamp(predicted, F::epsilon(), F::one() - F::epsilon());
+    -(actual_score * predicted.ln() + (F::one() - actual_score) * (F::one() - predicted).ln())
+}
+
+/// Calculates the mean [`log_loss`](fn.log_loss.html) of a series of predictions,
+/// each a predicted expected score paired with the actual score of the game.
+///
+/// Returns NaN if `predictions` is empty.
+pub fn mean_log_loss<F: Float>(predictions: &[(F, F)]) -> F {
+    let total = predictions
+        .iter()
+        .fold(F::zero(), |acc, &(predicted, actual_score)| {
+            acc + log_loss(predicted, actual_score)
+        });
+    total / cast(predictions.len() as f64)
+}
+
+/// Calculates the fraction of `population` that `rating` outranks by value.
+///
+/// Ratings in the population with exactly the same value as `rating` count as half outranked,
+/// so that ties are ranked consistently at their midpoint. If the population includes `rating` itself,
+/// it is treated as a tie. Deviations are ignored.
+///
+/// Returns NaN if `population` is empty.
+pub fn percentile<F: Float>(rating: &GlickoRating<F>, population: &[GlickoRating<F>]) -> F {
+    if population.is_empty() {
+        return F::nan();
+    }
+    let half: F = cast(0.5);
+    let outranked = population.iter().fold(F::zero(), |acc, other| {
+        match rating.cmp_by_value(other) {
+            Ordering::Greater => acc + F::one(),
+            Ordering::Equal => acc + half,
+            Ordering::Less => acc,
+        }
+    });
+    outranked / cast(population.len() as f64)
+}
+
+/// Combines two independent estimates of the rating of the same player or team into one.
+///
+/// Each rating is treated as a normal distribution over the skill of the player or team,
+/// and the two are multiplied together. That is, the values are averaged weighted by the
+/// inverse of the square of their deviations, so the more certain estimate counts for more,
+/// and the combined deviation is `1 / sqrt(1 / a.deviation² + 1 / b.deviation²)`, which is
+/// smaller than either. Volatility isn't part of the distribution, so the volatilities are
+/// averaged with the same weights as the values.
+///
+/// Note that this assumes the estimates are independent, such as ratings from separate game modes.
+/// Combining a rating with itself, or with an estimate derived from the same games,
+/// overstates the certainty of the result. Deviations of zero produce NaN.
+pub fn combine<F: Float>(a: Glicko2Rating<F>, b: Glicko2Rating<F>) -> Glicko2Rating<F> {
+    let precision_a = (a.deviation * a.deviation).recip();
+    let precision_b = (b.deviation * b.deviation).recip();
+    let precision = precision_a + precision_b;
+    Glicko2Rating {
+        value: (a.value * precision_a + b.value * precision_b) / precision,
+        deviation: precision.sqrt().recip(),
+        volatility: (a.volatility * precision_a + b.volatility * precision_b) / precision,
+    }
+}
+
+/// Combines the ratings of the members of a team into a single rating for the team.
+///
+/// The value of the team rating is the mean of the member values. The deviation and volatility
+/// are the root mean square of the member deviations and volatilities respectively, so that
+/// a team is exactly as uncertain as a typical member; a team of identical members has
+/// the same rating as each member. This assumes that the skill of a team is the average skill
+/// of its members, which is a reasonable starting point for team matchmaking, but may not suit every game.
+///
+/// Returns `None` if `members` is empty.
+pub fn team_rating<F: Float>(members: &[Glicko2Rating<F>]) -> Option<Glicko2Rating<F>> {
+    if members.is_empty() {
+        return None;
+    }
+    let count: F = cast(members.len() as f64);
+    let (value_sum, deviation_sum, volatility_sum) = members.iter().fold(
+        (F::zero(), F::zero(), F::zero()),
+        |(value_sum, deviation_sum, volatility_sum), member| {
+            (
+                value_sum + member.value,
+                deviation_sum + member.deviation * member.deviation,
+                volatility_sum + member.volatility * member.volatility,
+            )
+        },
+    );
+    Some(Glicko2Rating {
+        value: value_sum / count,
+        deviation: (deviation_sum / count).sqrt(),
+        volatility: (volatility_sum / count).sqrt(),
+    })
+}
+
+/// Calculates a new rating for a player or team that has not played in a rating period.
+///
+/// Only the deviation changes, growing to reflect the increased uncertainty in the rating.
+/// This is equivalent to calling [`new_rating`](fn.new_rating.html) with an empty slice of results,
+/// but makes the intent explicit when updating inactive players at the end of a rating period.
+pub fn apply_inactivity<F: Float>(prior_rating: Glicko2Rating<F>) -> Glicko2Rating<F> {
+    let new_rd = ((prior_rating.deviation * prior_rating.deviation)
+        + (prior_rating.volatility * prior_rating.volatility))
+        .sqrt();
+    Glicko2Rating {
+        value: prior_rating.value,
+        deviation: new_rd,
+        volatility: prior_rating.volatility,
+    }
+}
+
+/// Calculates a new rating for a player or team that has not played in `periods` rating periods.
+///
+/// This is equivalent to calling [`apply_inactivity`](fn.apply_inactivity.html) `periods` times,
+/// but is calculated directly as `sqrt(deviation² + periods * volatility²)` rather than with a loop.
+/// Consider capping the result with [`cap_deviation`](fn.cap_deviation.html), as the deviation
+/// grows without bound.
+pub fn apply_inactivity_periods<F: Float>(
+    prior_rating: Glicko2Rating<F>,
+    periods: u32,
+) -> Glicko2Rating<F> {
+    let periods: F = <F as NumCast>::from(periods).unwrap();
+    let new_rd = ((prior_rating.deviation * prior_rating.deviation)
+        + periods * (prior_rating.volatility * prior_rating.volatility))
+        .sqrt();
+    Glicko2Rating {
+        deviation: new_rd,
+        ..prior_rating
+    }
+}
+
+/// Moves the volatility of `rating` a fraction `rate` of the way towards `baseline`,
+/// such as for a player or team that has been inactive for a rating period.
+///
+/// The glicko2 system only ever changes volatility when games are played, so a single wild
+/// rating period can leave a rating with an extreme volatility indefinitely. Relaxing it towards
+/// a baseline, such as the suggested starting volatility of 0.06, over periods of inactivity
+/// means that a returning player or team isn't stuck with it. This isn't part of the glicko2
+/// system, so it isn't applied by [`apply_inactivity`](fn.apply_inactivity.html) or
+/// [`new_rating`](fn.new_rating.html); call it alongside them to opt in.
+///
+/// The new volatility is `volatility + rate * (baseline - volatility)`, so a `rate` of 0.0 leaves
+/// the volatility unchanged, and 1.0 resets it to `baseline`. `rate` is clamped into [0.0, 1.0].
+/// The value and deviation are unaffected.
+pub fn apply_volatility_decay<F: Float>(
+    rating: Glicko2Rating<F>,
+    baseline: F,
+    rate: F,
+) -> Glicko2Rating<F> {
+    let rate = num_traits::clamp(rate, F::zero(), F::one());
+    Glicko2Rating {
+        volatility: rating.volatility + rate * (baseline - rating.volatility),
+        ..rating
+    }
+}
+
+/// Calculates the deviation of `rating` after it has been idle for `days` days,
+/// where each rating period is `period_days` days long.
+///
+/// This is [`apply_inactivity_periods`](fn.apply_inactivity_periods.html) measured in days rather
+/// than rating periods: the deviation is `sqrt(deviation² + n * volatility²)`, where
+/// `n = days / period_days`. When `n` is fractional, the variance (the square of the deviation)
+/// is interpolated linearly between whole rating periods, as though uncertainty accumulates
+/// evenly over time, rather than rounding to a whole number of rating periods.
+/// Like the rest of the rating, the deviation is on the Glicko2 scale.
+///
+/// Negative or NaN `days` are treated as 0. If `period_days` is not positive, or is NaN,
+/// no rating periods are counted, so in either case the deviation is returned unchanged.
+pub fn deviation_after_idle<F: Float>(rating: Glicko2Rating<F>, days: F, period_days: F) -> F {
+    if period_days.is_nan() || period_days <= F::zero() {
+        return rating.deviation;
+    }
+    // `max` returns the other argument when one is NaN
+    let periods = days.max(F::zero()) / period_days;
+    ((rating.deviation * rating.deviation) + periods * (rating.volatility * rating.volatility))
+        .sqrt()
+}
+
+/// A rating along with the time at which it was calculated, for storing the history of a rating.
+///
+/// The time of a snapshot lets the growth in deviation since then be calculated, for players or
+/// teams that have been inactive. Rating periods are measured in time by a `period_length`,
+/// and needn't have elapsed completely; see [`deviation_after_idle`](fn.deviation_after_idle.html).
+#[cfg(feature = "chrono")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TimedRating<F = f64> {
+    /// The rating.
+    pub rating: Glicko2Rating<F>,
+    /// The time at which the rating was calculated.
+    pub at: DateTime<Utc>,
+}
+
+#[cfg(feature = "chrono")]
+impl<F: Float> TimedRating<F> {
+    /// Returns the number of rating periods, each `period_length` long, that have elapsed
+    /// between the snapshot `earlier` and this one.
+    ///
+    /// The result is fractional if the time between the snapshots isn't a whole number of rating
+    /// periods, and negative if `earlier` is in fact later. It is measured to the millisecond.
+    /// Returns `None` if `period_length` is shorter than a millisecond, including if it is
+    /// zero or negative.
+    pub fn periods_since(&self, earlier: &TimedRating<F>, period_length: TimeDelta) -> Option<F> {
+        periods_between(earlier.at, self.at, period_length)
+    }
+
+    /// Returns the deviation of this rating at time `now`, after growing with inactivity
+    /// since the rating was calculated, where each rating period is `period_length` long.
+    ///
+    /// This is [`deviation_after_idle`](fn.deviation_after_idle.html) with the idle time measured
+    /// from this snapshot. If `now` is before the snapshot, the deviation is returned unchanged.
+    /// Returns `None` if `period_length` is shorter than a millisecond, including if it is
+    /// zero or negative.
+    pub fn deviation_at(&self, now: DateTime<Utc>, period_length: TimeDelta) -> Option<F> {
+        let periods: F = periods_between(self.at, now, period_length)?;
+        Some(deviation_after_idle(self.rating, periods.max(F::zero()), F::one()))
+    }
+}
+
+#[cfg(feature = "chrono")]
+fn periods_between<F: Float>(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    period_length: TimeDelta,
+) -> Option<F> {
+    let period_length = period_length.num_milliseconds();
+    if period_length <= 0 {
+        return None;
+    }
+    let elapsed = (end - start).num_milliseconds() as f64;
+    Some(cast(elapsed / period_length as f64))
+}
+
+/// Caps the deviation of `rating` at `max_deviation`, which is on the Glicko2 scale.
+///
+/// After a long period of inactivity, the deviation of a rating can grow beyond that of an unrated
+/// player or team, which means that a returning player would be treated as more uncertain than a new one.
+/// To prevent this, it's common to cap deviations at that of an unrated player, 350 on the Glicko scale.
+/// Glicko scale deviations are converted to the Glicko2 scale by dividing by 173.7178,
+/// so this cap is approximately 2.0148 on the Glicko2 scale. It's easiest to supply it as
+/// `Glicko2Rating::unrated().deviation`.
+pub fn cap_deviation<F: Float>(rating: Glicko2Rating<F>, max_deviation: F) -> Glicko2Rating<F> {
+    Glicko2Rating {
+        deviation: rating.deviation.min(max_deviation),
+        ..rating
+    }
+}
+
+/// Calculates a new rating from an existing rating and a series of results.
+///
+/// If a player has not played in a rating period, new_rating should still be called
+/// with an empty slice so that the new rating deviation for that player is calculated.
+///
+/// `new_rating` never allocates, so the results can live anywhere, including in an array
+/// on the stack. The same is true of [`new_rating_with_config`](fn.new_rating_with_config.html)
+/// unless `compensated_summation` is set, which allocates to sort the terms of each sum.
+/// Combined with [`FixedRatingPeriod`](struct.FixedRatingPeriod.html) (behind the
+/// `heapless` feature), this allows ratings to be calculated where allocation is forbidden.
+///
+/// Unlike `GameResult`s, which can be constructed with a `Glicko2Rating` or a`GlickoRating`,
+/// `new_rating` requires a `Glicko2Rating`. This is because the volatility field present only in
+/// `Glicko2Rating` affects the result of the calculation. Using a default volatility can be done,
+/// but must be made explicit at the call site using the `Into<GlickoRating>` impl.
+/// Similarly, converting the final result back to a `GlickoRating` and thus losing data is left to
+/// the caller. Generally, converting back to a `GlickoRating` is only needed for display purposes.
+///
+/// `sys_constant` is best explained in the words of Mark Glickman himself:
+/// > The system constant, τ, which constrains the change in volatility over time, needs to be
+/// > set prior to application of the system. Reasonable choices are between 0.3 and 1.2,
+/// > though the system should be tested to decide which value results in greatest predictive
+/// > accuracy. Smaller values of τ prevent the volatility measures from changing by large
+/// > amounts, which in turn prevent enormous changes in ratings based on very improbable
+/// > results.
+///
+/// Before iterating towards the new volatility, the calculation searches for a range that contains it.
+/// For degenerate values of `sys_constant` (such as absurdly small values) this search may never succeed,
+/// so it is abandoned after 10,000 steps and the volatility is left unchanged. The iteration
+/// towards the new volatility is likewise stopped after 10,000 steps, keeping the best estimate,
+/// although it converges far sooner on any realistic input.
+/// Use [`try_new_rating`](fn.try_new_rating.html) to detect either case instead.
+///
+/// The estimated variance of the rating based only on the results, `v` in the glicko2 paper,
+/// is clamped between 1e-12 and 1e12. For a single game `v` is at least 4, even against an opponent
+/// with a deviation of zero, but a vast number of games could push it towards zero, and a rating gap
+/// so large that the expected score rounds to exactly 0 or 1 makes it infinite. Either extreme would
+/// otherwise produce an infinite or NaN rating. Results from ordinary inputs are unaffected.
+///
+/// A prior rating with a deviation of zero, such as one imported from a system that didn't track
+/// deviation, claims perfect certainty, which the glicko2 system doesn't allow for. Prior deviations
+/// below 0.000001 are therefore treated as 0.000001. The new deviation is then roughly the prior
+/// volatility, as the deviation of every rating grows by its volatility each rating period.
+pub fn new_rating<F: Float>(
+    prior_rating: Glicko2Rating<F>,
+    results: &[GameResult<F>],
+    sys_constant: F,
+) -> Glicko2Rating<F> {
+    new_rating_with_tolerance(prior_rating, results, sys_constant, cast(CONVERGENCE_TOLERANCE))
+}
+
+/// Calculates a new rating from an existing rating and the result of a single game.
+///
+/// This is identical to calling [`new_rating`](fn.new_rating.html) with a rating period
+/// consisting of only that game, and is convenient for applying games as they happen.
+/// No allocation is performed.
+pub fn new_rating_single<F: Float>(
+    prior_rating: Glicko2Rating<F>,
+    result: GameResult<F>,
+    sys_constant: F,
+) -> Glicko2Rating<F> {
+    new_rating(prior_rating, &[result], sys_constant)
+}
+
+/// Calculates a new rating from an existing rating and an iterator of results.
+///
+/// This is convenient when results are streamed from elsewhere, such as a database cursor,
+/// as the caller doesn't need to collect them first. However, the calculation makes several
+/// passes over the results, so they are still collected into a `Vec` internally.
+///
+/// See [`new_rating`](fn.new_rating.html) for details.
+pub fn new_rating_iter<F: Float, I: IntoIterator<Item = GameResult<F>>>(
+    prior_rating: Glicko2Rating<F>,
+    results: I,
+    sys_constant: F,
+) -> Glicko2Rating<F> {
+    let results: Vec<GameResult<F>> = results.into_iter().collect();
+    new_rating(prior_rating, &results, sys_constant)
+}
+
+/// Calculates a new rating from an existing rating and a series of results,
+/// using [`DEFAULT_SYS_CONSTANT`](constant.DEFAULT_SYS_CONSTANT.html) as the system constant.
+///
+/// See [`new_rating`](fn.new_rating.html) for details.
+pub fn new_rating_default<F: Float>(
+    prior_rating: Glicko2Rating<F>,
+    results: &[GameResult<F>],
+) -> Glicko2Rating<F> {
+    new_rating(prior_rating, results, cast(DEFAULT_SYS_CONSTANT))
+}
+
+/// Calculates a new rating from an existing rating and a series of results,
+/// returning it both on the Glicko2 scale, for storage, and on the Glicko scale, for display.
+///
+/// The `GlickoRating` is always the conversion of the returned `Glicko2Rating`.
+/// See [`new_rating`](fn.new_rating.html) for details.
+pub fn new_rating_dual<F: Float>(
+    prior_rating: Glicko2Rating<F>,
+    results: &[GameResult<F>],
+    sys_constant: F,
+) -> (Glicko2Rating<F>, GlickoRating<F>) {
+    let rating = new_rating(prior_rating, results, sys_constant);
+    (rating, GlickoRating::from(rating))
+}
+
+/// Calculates a new rating from an existing rating and a series of results,
+/// preventing the rating value from dropping below `floor`, which is on the Glicko scale.
+///
+/// Many organizations enforce a rating floor like this. The floor is applied after the usual
+/// calculation, by raising the new rating value to the Glicko2 equivalent of `floor` if it is below it,
+/// so that converting the result to a `GlickoRating` gives a value of `floor` (up to floating point
+/// rounding). The deviation and volatility are unaffected.
+///
+/// Note that the prior rating is not checked against the floor, so a prior rating already below
+/// the floor will be raised to it. See [`new_rating`](fn.new_rating.html) for other details.
+pub fn new_rating_with_floor<F: Float>(
+    prior_rating: Glicko2Rating<F>,
+    results: &[GameResult<F>],
+    sys_constant: F,
+    floor: F,
+) -> Glicko2Rating<F> {
+    let rating = new_rating(prior_rating, results, sys_constant);
+    let params = ScaleParams::<F>::standard();
+    let glicko2_floor = (floor - params.base) / params.scale;
+    Glicko2Rating {
+        value: rating.value.max(glicko2_floor),
+        ..rating
+    }
+}
+
+/// Calculates a new rating from an existing rating and a series of results,
+/// limiting the total influence of the results to that of `max_effective_games` games.
+///
+/// The effective number of games in a rating period is the sum of the weights of its results
+/// (see [`GameResult::weighted`](struct.GameResult.html#method.weighted)), which is simply the
+/// number of games unless weights are used. If this total `W` exceeds `max_effective_games` `N`,
+/// the weight of every result is multiplied by `N / W`. As each game contributes its weight
+/// times `g(φj)² E (1 - E)` to `1 / v` and its weight times `g(φj) (s - E)` to the improvement
+/// `Δ / v`, both sums shrink by a factor of `N / W`. So the results keep their relative
+/// importance, and the rating moves as if only `N` games with the same average outcome
+/// had been played. This stops very active players from swinging or farming their rating within
+/// a single rating period. Periods with no more than `N` effective games are unaffected.
+///
+/// A `max_effective_games` that is not positive, or is NaN, lets no results count at all,
+/// so the rating is updated as for a rating period in which no games were played.
+///
+/// See [`new_rating`](fn.new_rating.html) for other details.
+pub fn new_rating_capped<F: Float>(
+    prior_rating: Glicko2Rating<F>,
+    results: &[GameResult<F>],
+    sys_constant: F,
+    max_effective_games: F,
+) -> Glicko2Rating<F> {
+    if max_effective_games.is_nan() || max_effective_games <= F::zero() {
+        return new_rating(prior_rating, &[], sys_constant);
+    }
+    let effective_games = results
+        .iter()
+        .fold(F::zero(), |acc, result| acc + result.weight);
+    if effective_games <= max_effective_games {
+        return new_rating(prior_rating, results, sys_constant);
+    }
+    let scale = max_effective_games / effective_games;
+    let results: Vec<GameResult<F>> = results
+        .iter()
+        .map(|result| GameResult {
+            weight: result.weight * scale,
+            ..*result
+        })
+        .collect();
+    new_rating(prior_rating, &results, sys_constant)
+}
+
+/// Calculates a new rating from an existing rating and a series of results,
+/// limiting the change in rating value to at most `max_change_glicko` in either direction,
+/// on the Glicko scale.
+///
+/// This is a safety rail against shocking jumps in rating, such as for a public ladder.
+/// The usual calculation is performed, and then the change in value is clamped:
+/// a `max_change_glicko` of 100 keeps the new value within 100 points of the prior value once
+/// converted to a `GlickoRating` (up to floating point rounding). The limit is converted to the
+/// Glicko2 scale by dividing by 173.7178, and the clamped value is stored on the Glicko2 scale
+/// as usual. The deviation and volatility are updated normally, as though the full change
+/// had been applied.
+///
+/// See [`new_rating`](fn.new_rating.html) for other details, and
+/// [`new_rating_capped`](fn.new_rating_capped.html) for limiting the influence of many games
+/// instead.
+///
+/// A `max_change_glicko` that is negative, or is NaN, is treated as 0, so the value does not
+/// change at all.
+pub fn new_rating_clamped_change<F: Float>(
+    prior_rating: Glicko2Rating<F>,
+    results: &[GameResult<F>],
+    sys_constant: F,
+    max_change_glicko: F,
+) -> Glicko2Rating<F> {
+    let rating = new_rating(prior_rating, results, sys_constant);
+    // `max` returns the other argument when one is NaN
+    let max_change = max_change_glicko.max(F::zero()) / cast(GLICKO2_SCALE);
+    let change = num_traits::clamp(rating.value - prior_rating.value, -max_change, max_change);
+    Glicko2Rating {
+        value: prior_rating.value + change,
+        ..rating
+    }
+}
+
+/// A new rating, along with how it changed from the prior rating.
+///
+/// The changes are on the Glicko2 scale, but can be converted to the Glicko scale
+/// with [`glicko_value_delta`](#method.glicko_value_delta) and
+/// [`glicko_deviation_delta`](#method.glicko_deviation_delta).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RatingChange<F = f64> {
+    /// The new rating.
+    pub rating: Glicko2Rating<F>,
+    /// The new rating value minus the prior rating value.
+    pub value_delta: F,
+    /// The new rating deviation minus the prior rating deviation.
+    pub deviation_delta: F,
+}
+
+impl<F: Float> RatingChange<F> {
+    /// Returns the change in rating value on the Glicko scale.
+    pub fn glicko_value_delta(&self) -> F {
+        self.value_delta * cast(GLICKO2_SCALE)
+    }
+
+    /// Returns the change in rating deviation on the Glicko scale.
+    pub fn glicko_deviation_delta(&self) -> F {
+        self.deviation_delta * cast(GLICKO2_SCALE)
+    }
+
+    /// Returns the change in rating, without the new rating.
+    pub fn delta(&self) -> RatingDelta<F> {
+        RatingDelta {
+            value: self.value_delta,
+            deviation: self.deviation_delta,
+        }
+    }
+}
+
+/// A change in rating, on the Glicko2 scale.
+///
+/// Changes can be added together, for example to find the total change in rating over a season:
+///
+/// ```
+/// # use glicko2::{new_rating_with_delta, GameResult, Glicko2Rating, GlickoRating, RatingDelta};
+/// let mut rating = Glicko2Rating::unrated();
+/// let opponent: GlickoRating = GlickoRating::unrated();
+/// let mut season = RatingDelta::default();
+/// for _ in 0..3 {
+///     let change = new_rating_with_delta(rating, &[GameResult::win(opponent)], 0.5);
+///     rating = change.rating;
+///     season += change.delta();
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RatingDelta<F = f64> {
+    /// The change in rating value.
+    pub value: F,
+    /// The change in rating deviation.
+    pub deviation: F,
+}
+
+impl<F: Float> RatingDelta<F> {
+    /// Returns the change in rating value on the Glicko scale.
+    pub fn glicko_value(&self) -> F {
+        self.value * cast(GLICKO2_SCALE)
+    }
+
+    /// Returns the change in rating deviation on the Glicko scale.
+    pub fn glicko_deviation(&self) -> F {
+        self.deviation * cast(GLICKO2_SCALE)
+    }
+}
+
+impl<F: Float> Add for RatingDelta<F> {
+    type Output = RatingDelta<F>;
+
+    fn add(self, other: RatingDelta<F>) -> RatingDelta<F> {
+        RatingDelta {
+            value: self.value + other.value,
+            deviation: self.deviation + other.deviation,
+        }
+    }
+}
+
+impl<F: Float> AddAssign for RatingDelta<F> {
+    fn add_assign(&mut self, other: RatingDelta<F>) {
+        *self = *self + other;
+    }
+}
+
+impl<F: Float> Sum for RatingDelta<F> {
+    fn sum<I: Iterator<Item = RatingDelta<F>>>(iter: I) -> RatingDelta<F> {
+        iter.fold(
+            RatingDelta {
+                value: F::zero(),
+                deviation: F::zero(),
+            },
+            Add::add,
+        )
+    }
+}
+
+/// Calculates a new rating from an existing rating and a series of results,
+/// along with how the rating changed.
+///
+/// See [`new_rating`](fn.new_rating.html) for details.
+pub fn new_rating_with_delta<F: Float>(
+    prior_rating: Glicko2Rating<F>,
+    results: &[GameResult<F>],
+    sys_constant: F,
+) -> RatingChange<F> {
+    let rating = new_rating(prior_rating, results, sys_constant);
+    RatingChange {
+        rating,
+        value_delta: rating.value - prior_rating.value,
+        deviation_delta: rating.deviation - prior_rating.deviation,
+    }
+}
+
+/// Calculates a new rating from an existing rating and a record of `wins`, `draws` and `losses`
+/// against a single opponent with rating `opponent`, all within one rating period.
+///
+/// This is convenient for a record like "7-1-2 against the 1800 bot", and gives the same result as
+/// [`new_rating`](fn.new_rating.html) with that many separate results (up to floating point
+/// rounding). Rather than building a result for every game, a
+/// [weighted](struct.GameResult.html#method.weighted) result stands in for each of the wins,
+/// draws and losses, so no allocation is performed. A record of no games at all is treated as
+/// a rating period in which no games were played.
+pub fn new_rating_vs<F: Float>(
+    prior_rating: Glicko2Rating<F>,
+    opponent: Glicko2Rating<F>,
+    wins: u32,
+    draws: u32,
+    losses: u32,
+    sys_constant: F,
+) -> Glicko2Rating<F> {
+    if wins == 0 && draws == 0 && losses == 0 {
+        return new_rating(prior_rating, &[], sys_constant);
+    }
+    let count = |games: u32| -> F { <F as NumCast>::from(games).unwrap() };
+    let results = [
+        GameResult::weighted(opponent, F::one(), count(wins)),
+        GameResult::weighted(opponent, cast(0.5), count(draws)),
+        GameResult::weighted(opponent, F::zero(), count(losses)),
+    ];
+    new_rating(prior_rating, &results, sys_constant)
+}
+
+/// Calculates how much the rating value of a player or team with rating `prior_rating`
+/// would change, on the Glicko scale, if they won a single game against `opponent`.
+///
+/// This is the rating value after [`new_rating`](fn.new_rating.html) is applied to a rating
+/// period consisting of only that win, minus the prior rating value. It is useful for previewing
+/// how much a game is worth, such as in matchmaking: wins against stronger or more certain
+/// opponents are worth more. The gain is always positive.
+pub fn marginal_gain<F: Float>(
+    prior_rating: Glicko2Rating<F>,
+    opponent: Glicko2Rating<F>,
+    sys_constant: F,
+) -> F {
+    new_rating_with_delta(prior_rating, &[GameResult::win(opponent)], sys_constant)
+        .glicko_value_delta()
+}
+
+/// Calculates the rating of a player or team after each of a series of rating periods.
+///
+/// Starting from `start`, [`new_rating`](fn.new_rating.html) is applied with the results of
+/// each entry of `periods` in turn, carrying the new rating forward into the next period.
+/// The returned ratings are the rating after each period, in the same order,
+/// which is convenient for charting a rating over time. An empty entry in `periods`
+/// represents a rating period in which no games were played.
+pub fn rating_history<F: Float>(
+    start: Glicko2Rating<F>,
+    periods: &[Vec<GameResult<F>>],
+    sys_constant: F,
+) -> Vec<Glicko2Rating<F>> {
+    let mut rating = start;
+    periods
+        .iter()
+        .map(|results| {
+            rating = new_rating(rating, results, sys_constant);
+            rating
+        })
+        .collect()
+}
+
+/// Calculates new ratings for many players or teams at the end of a rating period.
+///
+/// Each entry of `players` is the rating of a player or team before the rating period,
+/// paired with the results of their games in that period. The returned ratings are in the same order.
+/// Because `GameResult`s capture the rating of the opponent when they are constructed,
+/// every update uses the ratings of opponents from before the rating period,
+/// as the glicko2 system requires.
+///
+/// This is equivalent to calling [`new_rating`](fn.new_rating.html) for each player or team.
+pub fn new_ratings<F: Float>(
+    players: &[(Glicko2Rating<F>, &[GameResult<F>])],
+    sys_constant: F,
+) -> Vec<Glicko2Rating<F>> {
+    players
+        .iter()
+        .map(|&(prior_rating, results)| new_rating(prior_rating, results, sys_constant))
+        .collect()
+}
+
+/// Calculates new ratings for many players or teams at the end of a rating period, in parallel.
+///
+/// This behaves identically to [`new_ratings`](fn.new_ratings.html), but spreads the work
+/// across the rayon thread pool. This is only worthwhile for large numbers of players or teams.
+#[cfg(feature = "rayon")]
+pub fn par_new_ratings<F: Float + Send + Sync>(
+    players: &[(Glicko2Rating<F>, &[GameResult<F>])],
+    sys_constant: F,
+) -> Vec<Glicko2Rating<F>> {
+    players
+        .par_iter()
+        .map(|&(prior_rating, results)| new_rating(prior_rating, results, sys_constant))
+        .collect()
+}
+
+/// Calculates a new rating from an existing rating and a series of results,
+/// using `tolerance` as the convergence tolerance of the iterative volatility calculation.
+///
+/// [`new_rating`](fn.new_rating.html) uses a tolerance of
+/// [`CONVERGENCE_TOLERANCE`](constant.CONVERGENCE_TOLERANCE.html), 0.000001. A larger tolerance
+/// makes for fewer iterations and thus faster updates, at the cost of a less precise volatility,
+/// which may be acceptable for very large leaderboards. A smaller tolerance makes for more
+/// precise results, at the cost of more iterations.
+///
+/// A positive tolerance smaller than the machine epsilon of `F` is raised to the machine epsilon.
+///
+/// # Panics
+///
+/// Panics if `tolerance` is not positive, or is NaN. Use
+/// [`try_new_rating_with_config`](fn.try_new_rating_with_config.html) to get an error instead.
+pub fn new_rating_with_tolerance<F: Float>(
+    prior_rating: Glicko2Rating<F>,
+    results: &[GameResult<F>],
+    sys_constant: F,
+    tolerance: F,
+) -> Glicko2Rating<F> {
+    new_rating_with_config(
+        prior_rating,
+        results,
+        RatingConfig {
+            tolerance,
+            ..RatingConfig::new(sys_constant)
+        },
+    )
+}
+
+/// Options that customize the calculation performed by
+/// [`new_rating_with_config`](fn.new_rating_with_config.html).
+///
+/// Construct it with [`new`](#method.new) and override the fields of interest, such as
+/// `RatingConfig { max_opponent_deviation: Some(1.0), ..RatingConfig::new(0.5) }`,
+/// so that code keeps compiling as new options are added.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RatingConfig<F = f64> {
+    /// The system constant τ. See [`new_rating`](fn.new_rating.html).
+    pub sys_constant: F,
+    /// The convergence tolerance of the iterative volatility calculation.
+    /// See [`new_rating_with_tolerance`](fn.new_rating_with_tolerance.html).
+    /// It must be positive.
+    pub tolerance: F,
+    /// If set, opponent deviations larger than this (on the Glicko2 scale) are replaced by it.
+    ///
+    /// Games against opponents with very uncertain ratings, such as unrated opponents,
+    /// barely affect the rating of the player or team, which is correct but may be undesirable
+    /// in some settings, like tournaments with many new players. Capping opponent deviations
+    /// at a provisional value makes these games count for more.
+    pub max_opponent_deviation: Option<F>,
+    /// If set, new volatilities smaller than this are replaced by it.
+    /// See [`max_volatility`](#structfield.max_volatility).
+    pub min_volatility: Option<F>,
+    /// If set, new volatilities larger than this are replaced by it.
+    ///
+    /// In small leagues, a few improbable results can drive volatilities, and in turn deviations,
+    /// up rapidly. Bounding the new volatility prevents such extreme swings. Bounds of around
+    /// 0.03 and 0.15, either side of the suggested starting volatility of 0.06, are a reasonable
+    /// starting point, but as with the system constant, should be tested for predictive accuracy.
+    pub max_volatility: Option<F>,
+    /// If true, the sums over the results are made independent of the order of the results.
+    ///
+    /// Floating point addition isn't associative, so by default reordering the results of a
+    /// rating period can change the new rating in its last few bits. When this is set,
+    /// the terms of each sum are sorted by magnitude and added with Kahan compensated summation,
+    /// so that the same results always produce exactly the same rating, whatever their order,
+    /// and rounding error is reduced. This is useful where ratings must be reproducible,
+    /// at the cost of an allocation and a sort per sum.
+    pub compensated_summation: bool,
+    /// If true, the volatility is left unchanged rather than recalculated,
+    /// and `sys_constant`, `tolerance` and the volatility bounds have no effect.
+    /// See [`new_rating_fixed_volatility`](fn.new_rating_fixed_volatility.html).
+    pub fixed_volatility: bool,
+    /// If true, the new volatility is found by bisection rather than by the Illinois algorithm.
+    ///
+    /// The Illinois algorithm usually converges in a handful of iterations, and falls back to
+    /// bisection by itself if it stalls, so this is rarely needed. Bisection takes more
+    /// iterations, but converges steadily on any input, which suits applications that
+    /// prioritize robustness over speed.
+    pub bisection: bool,
+}
+
+impl<F: Float> RatingConfig<F> {
+    /// Constructs a `RatingConfig` with the system constant `sys_constant`,
+    /// which otherwise behaves exactly like [`new_rating`](fn.new_rating.html).
+    pub fn new(sys_constant: F) -> RatingConfig<F> {
+        RatingConfig {
+            sys_constant,
+            tolerance: cast(CONVERGENCE_TOLERANCE),
+            max_opponent_deviation: None,
+            min_volatility: None,
+            max_volatility: None,
+            compensated_summation: false,
+            fixed_volatility: false,
+            bisection: false,
+        }
+    }
+
+    fn opponent_deviation(&self, result: &GameResult<F>) -> F {
+        match self.max_opponent_deviation {
+            Some(max_opponent_deviation) => {
+                result.opponent_rating_deviation.min(max_opponent_deviation)
+            }
+            None => result.opponent_rating_deviation,
+        }
+    }
+
+    fn sum<I: Iterator<Item = F>>(&self, terms: I) -> F {
+        if !self.compensated_summation {
+            return terms.fold(F::zero(), |acc, term| acc + term);
+        }
+        let mut terms: Vec<F> = terms.collect();
+        terms.sort_by(|a, b| cmp_values(a.abs(), b.abs()).then(cmp_values(*a, *b)));
+        let mut sum = F::zero();
+        let mut compensation = F::zero();
+        for term in terms {
+            let compensated_term = term - compensation;
+            let new_sum = sum + compensated_term;
+            compensation = (new_sum - sum) - compensated_term;
+            sum = new_sum;
+        }
+        sum
+    }
+
+    fn bound_volatility(&self, volatility: F) -> F {
+        let volatility = match self.min_volatility {
+            Some(min_volatility) => volatility.max(min_volatility),
+            None => volatility,
+        };
+        match self.max_volatility {
+            Some(max_volatility) => volatility.min(max_volatility),
+            None => volatility,
+        }
+    }
+}
+
+impl<F: Float> Default for RatingConfig<F> {
+    fn default() -> RatingConfig<F> {
+        RatingConfig::new(cast(DEFAULT_SYS_CONSTANT))
+    }
+}
+
+/// Calculates a new rating from an existing rating and a series of results,
+/// customizing the calculation with `config`.
+///
+/// See [`new_rating`](fn.new_rating.html) for details.
+///
+/// # Panics
+///
+/// Panics if `config.tolerance` is not positive, or is NaN. Use
+/// [`try_new_rating_with_config`](fn.try_new_rating_with_config.html) to get an error instead.
+pub fn new_rating_with_config<F: Float>(
+    prior_rating: Glicko2Rating<F>,
+    results: &[GameResult<F>],
+    config: RatingConfig<F>,
+) -> Glicko2Rating<F> {
+    assert!(
+        validate_tolerance(config.tolerance).is_ok(),
+        "convergence tolerance must be positive"
+    );
+    match new_rating_inner(
+        prior_rating,
+        results,
+        config,
+        None,
+        &mut SolverStats::default(),
+    ) {
+        Ok(rating) => rating,
+        Err(_) => unreachable!("volatility calculation without an iteration limit can't fail"),
+    }
+}
+
+/// Calculates a new rating from an existing rating and a series of results,
+/// keeping the volatility of the rating fixed.
+///
+/// Only the rating value and deviation are updated, as in simplified deployments of glicko2 that
+/// deliberately freeze volatility. The iterative volatility calculation is skipped entirely,
+/// which makes this faster than [`new_rating`](fn.new_rating.html), and rules out runaway
+/// volatilities. As the system constant only constrains changes in volatility, none is needed.
+///
+/// The deviation still grows by the prior volatility before being reduced by the results,
+/// so the volatility should be set to a sensible value, such as the suggested 0.06.
+/// See `new_rating` for other details.
+pub fn new_rating_fixed_volatility<F: Float>(
+    prior_rating: Glicko2Rating<F>,
+    results: &[GameResult<F>],
+) -> Glicko2Rating<F> {
+    new_rating_with_config(
+        prior_rating,
+        results,
+        RatingConfig {
+            fixed_volatility: true,
+            ..RatingConfig::default()
+        },
+    )
+}
+
+/// Calculates the estimated variance of a rating based only on a series of results,
+/// `v` in the glicko2 paper.
+///
+/// This is an intermediate quantity of [`new_rating`](fn.new_rating.html), exposed for testing and
+/// research. It is on the Glicko2 scale, and is clamped as described by `new_rating`.
+/// It is only meaningful if `results` is not empty.
+pub fn compute_v<F: Float>(prior_rating: Glicko2Rating<F>, results: &[GameResult<F>]) -> F {
+    estimated_variance(prior_rating, results, &RatingConfig::default())
+}
+
+/// Calculates the estimated improvement in rating based only on a series of results,
+/// `Δ` in the glicko2 paper.
+///
+/// This is an intermediate quantity of [`new_rating`](fn.new_rating.html), exposed for testing and
+/// research. It is on the Glicko2 scale, and is only meaningful if `results` is not empty.
+pub fn compute_delta<F: Float>(prior_rating: Glicko2Rating<F>, results: &[GameResult<F>]) -> F {
+    let config = RatingConfig::default();
+    estimated_variance(prior_rating, results, &config)
+        * improvement_sum(prior_rating, results, &config)
+}
+
+/// Calculates a new rating from an existing rating and a series of results,
+/// giving up if the volatility calculation fails to converge.
+///
+/// This behaves identically to [`new_rating`](fn.new_rating.html), except that the iterative
+/// volatility calculation is capped at 10,000 iterations, as is the search for the range containing
+/// the new volatility. If either cap is hit, `Glicko2Error::NonConvergence` is returned
+/// instead of continuing to loop.
+///
+/// The inputs are also checked before any calculation is done, which makes this appropriate
+/// for use with untrusted input, such as in a server context. `sys_constant` is checked too,
+/// as a mistaken value silently produces nonsensical ratings. Glickman recommends values
+/// between 0.3 and 1.2, while much larger values let the volatility collapse towards zero.
+///
+/// In full, an error is returned if:
+///
+/// - `sys_constant` is not positive, is larger than 10, or is NaN
+///   (`Glicko2Error::InvalidSysConstant`);
+/// - the prior rating is invalid, as checked by
+///   [`Glicko2Rating::try_new`](struct.Glicko2Rating.html#method.try_new): a value, deviation or
+///   volatility that is NaN or infinite (`Glicko2Error::NonFinite`), a negative deviation
+///   (`Glicko2Error::InvalidDeviation`), or a volatility that is not positive
+///   (`Glicko2Error::InvalidVolatility`);
+/// - any result has an opponent value, opponent deviation or weight that is NaN or infinite
+///   (`Glicko2Error::NonFinite`), or a negative opponent deviation
+///   (`Glicko2Error::InvalidDeviation`);
+/// - any result has a score that is outside of the range [0.0, 1.0], or is NaN
+///   (`Glicko2Error::InvalidScore`);
+/// - either cap on the volatility calculation is hit (`Glicko2Error::NonConvergence`).
+pub fn try_new_rating<F: Float>(
+    prior_rating: Glicko2Rating<F>,
+    results: &[GameResult<F>],
+    sys_constant: F,
+) -> Result<Glicko2Rating<F>, Glicko2Error> {
+    try_new_rating_with_config(prior_rating, results, RatingConfig::new(sys_constant))
+}
+
+/// Calculates a new rating from an existing rating and a series of results,
+/// customizing the calculation with `config` and giving up if the volatility calculation
+/// fails to converge.
+///
+/// This is the fallible equivalent of [`new_rating_with_config`](fn.new_rating_with_config.html),
+/// and checks its inputs exactly as [`try_new_rating`](fn.try_new_rating.html) does, using
+/// `config.sys_constant` as the system constant. It also returns
+/// `Glicko2Error::InvalidTolerance` if `config.tolerance` is not positive, or is NaN.
+pub fn try_new_rating_with_config<F: Float>(
+    prior_rating: Glicko2Rating<F>,
+    results: &[GameResult<F>],
+    config: RatingConfig<F>,
+) -> Result<Glicko2Rating<F>, Glicko2Error> {
+    let sys_constant = config.sys_constant;
+    // Written to also reject NaN.
+    if !(sys_constant > F::zero() && sys_constant <= cast(MAX_SYS_CONSTANT)) {
+        return Err(Glicko2Error::InvalidSysConstant);
+    }
+    validate_tolerance(config.tolerance)?;
+    Glicko2Rating::try_new(
+        prior_rating.value,
+        prior_rating.deviation,
+        prior_rating.volatility,
+    )?;
+    for result in results {
+        validate_result(result)?;
+    }
+    new_rating_inner(
+        prior_rating,
+        results,
+        config,
+        Some(MAX_ITERATIONS),
+        &mut SolverStats::default(),
+    )
+}
+
+/// Statistics about the iterative volatility calculation performed while calculating a new rating.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SolverStats {
+    /// The number of iterations of the Illinois algorithm, or of bisection,
+    /// performed to find the new volatility.
+    pub iterations: u32,
+    /// The number of steps taken searching for the range containing the new volatility.
+    /// This is always zero if `used_shortcut` is true.
+    pub bracket_iterations: u32,
+    /// Whether the range containing the new volatility was found directly,
+    /// which happens when `delta² > deviation² + v`.
+    pub used_shortcut: bool,
+    /// Whether bisection was used to find the new volatility, either because
+    /// [`RatingConfig::bisection`](struct.RatingConfig.html#structfield.bisection) was set,
+    /// or because the Illinois algorithm stalled.
+    pub used_bisection: bool,
+}
+
+/// Calculates a new rating from an existing rating and a series of results,
+/// along with statistics about the volatility calculation.
+///
+/// This is useful for profiling, tuning the system constant, and investigating inputs
+/// that converge slowly. If `results` is empty, no volatility calculation is performed
+/// and the returned statistics are all zero.
+///
+/// See [`new_rating`](fn.new_rating.html) for details.
+pub fn new_rating_instrumented<F: Float>(
+    prior_rating: Glicko2Rating<F>,
+    results: &[GameResult<F>],
+    sys_constant: F,
+) -> (Glicko2Rating<F>, SolverStats) {
+    let mut stats = SolverStats::default();
+    match new_rating_inner(
+        prior_rating,
+        results,
+        RatingConfig::new(sys_constant),
+        None,
+        &mut stats,
+    ) {
+        Ok(rating) => (rating, stats),
+        Err(_) => unreachable!("volatility calculation without an iteration limit can't fail"),
+    }
+}
+
+fn new_rating_inner<F: Float>(
+    prior_rating: Glicko2Rating<F>,
+    results: &[GameResult<F>],
+    config: RatingConfig<F>,
+    max_iterations: Option<u32>,
+    stats: &mut SolverStats,
+) -> Result<Glicko2Rating<F>, Glicko2Error> {
+    let prior_rating = Glicko2Rating {
+        deviation: prior_rating.deviation.max(cast(MIN_DEVIATION)),
+        ..prior_rating
+    };
+    if !results.is_empty() {
+        let v = estimated_variance(prior_rating, results, &config);
+        let improvement = improvement_sum(prior_rating, results, &config);
+        let delta = v * improvement;
+        let new_volatility = if config.fixed_volatility {
+            prior_rating.volatility
+        } else {
+            let new_volatility =
+                new_volatility(prior_rating, delta, v, &config, max_iterations, stats)?;
+            config.bound_volatility(new_volatility)
+        };
+        let new_pre_rd = ((prior_rating.deviation * prior_rating.deviation)
+            + (new_volatility * new_volatility))
+            .sqrt();
+        let new_rd = {
+            let subexpr_1 = (new_pre_rd * new_pre_rd).recip();
+            let subexpr_2 = v.recip();
+            (subexpr_1 + subexpr_2).sqrt().recip()
+        };
+        let new_rating = prior_rating.value + (new_rd * new_rd) * improvement;
+        Ok(Glicko2Rating {
+            value: new_rating,
+            deviation: new_rd,
+            volatility: new_volatility,
+        })
+    } else {
+        Ok(apply_inactivity(prior_rating))
+    }
+}
+
+fn estimated_variance<F: Float>(
+    prior_rating: Glicko2Rating<F>,
+    results: &[GameResult<F>],
+    config: &RatingConfig<F>,
+) -> F {
+    let v = config
+        .sum(results.iter().map(|result| {
+            let opponent_deviation = config.opponent_deviation(result);
+            let expected =
+                e(prior_rating.value, result.opponent_rating_value, opponent_deviation);
+            result.weight
+                * g(opponent_deviation)
+                * g(opponent_deviation)
+                * expected
+                * (F::one() - expected)
+        }))
+        .recip();
+    num_traits::clamp(v, cast(MIN_VARIANCE), cast(MAX_VARIANCE))
+}
+
+fn improvement_sum<F: Float>(
+    prior_rating: Glicko2Rating<F>,
+    results: &[GameResult<F>],
+    config: &RatingConfig<F>,
+) -> F {
+    config.sum(results.iter().map(|result| {
+        let opponent_deviation = config.opponent_deviation(result);
+        result.weight
+            * g(opponent_deviation)
+            * (result.score
+                - e(prior_rating.value, result.opponent_rating_value, opponent_deviation))
+    }))
+}
+
+fn new_volatility<F: Float>(
+    prior_rating: Glicko2Rating<F>,
+    delta: F,
+    v: F,
+    config: &RatingConfig<F>,
+    max_iterations: Option<u32>,
+    stats: &mut SolverStats,
+) -> Result<F, Glicko2Error> {
+    let sys_constant = config.sys_constant;
+    let objective = |x| {
+        volatility_objective(
+            x,
+            delta,
+            prior_rating.deviation,
+            v,
+            prior_rating.volatility,
+            sys_constant,
+        )
+    };
+    let a = (prior_rating.volatility * prior_rating.volatility).ln();
+    let delta_squared = delta * delta;
+    let rd_squared = prior_rating.deviation * prior_rating.deviation;
+    let b = if delta_squared > rd_squared + v {
+        stats.used_shortcut = true;
+        (delta_squared - rd_squared - v).ln()
+    } else {
+        let mut k = F::one();
+        let mut bracket_iterations = 0;
+        while objective(a - k * sys_constant) < F::zero() {
+            if bracket_iterations >= max_iterations.unwrap_or(MAX_ITERATIONS) {
+                // Degenerate inputs, such as an absurdly small system constant, can prevent
+                // the bracket from ever being found. Rather than loop forever, either give up
+                // or fall back to leaving the volatility unchanged.
+                return match max_iterations {
+                    Some(_) => Err(Glicko2Error::NonConvergence {
+                        iterations: bracket_iterations,
+                    }),
+                    None => Ok(prior_rating.volatility),
+                };
+            }
+            bracket_iterations += 1;
+            stats.bracket_iterations = bracket_iterations;
+            k = k + F::one();
+        }
+        a - k * sys_constant
+    };
+    let x = find_root(
+        objective,
+        a,
+        b,
+        config.tolerance.max(F::epsilon()),
+        config.bisection,
+        max_iterations,
+        stats,
+    )?;
+    Ok((x / cast(2.0)).exp())
+}
+
+// Finds a root of `objective` between `a` and `b`, which must bracket one,
+// using the Illinois algorithm (steps 5.1 through 5.4 of the glicko2 paper).
+//
+// The Illinois algorithm converges quickly on well-behaved functions, but can stall when
+// one end of the bracket barely moves. If the bracket hasn't halved in width over
+// `STALL_ITERATIONS` iterations, it switches to plain bisection, which always converges.
+fn find_root<F: Float, O: Fn(F) -> F>(
+    objective: O,
+    mut a: F,
+    mut b: F,
+    tolerance: F,
+    mut bisection: bool,
+    max_iterations: Option<u32>,
+    stats: &mut SolverStats,
+) -> Result<F, Glicko2Error> {
+    let mut fa = objective(a);
+    let mut fb = objective(b);
+    let mut iterations = 0;
+    let mut stall_check_width = (b - a).abs();
+    // An absolute tolerance can be finer than the gap between adjacent floats near the root,
+    // particularly for `f32`, so never ask for more precision than the float type has.
+    while (b - a).abs() > tolerance.max(F::epsilon() * a.abs().max(b.abs())) {
+        if iterations >= max_iterations.unwrap_or(MAX_ITERATIONS) {
+            // Without an explicit limit, settle for the best estimate so far rather than loop
+            // forever, as bisection halves the bracket each step and converges long before this.
+            return match max_iterations {
+                Some(_) => Err(Glicko2Error::NonConvergence { iterations }),
+                None => Ok(a),
+            };
+        }
+        iterations += 1;
+        stats.iterations = iterations;
+        if !bisection && iterations % STALL_ITERATIONS == 0 {
+            let width = (b - a).abs();
+            if width > stall_check_width / cast(2.0) {
+                bisection = true;
+            }
+            stall_check_width = width;
+        }
+        stats.used_bisection |= bisection;
+        // a
+        let c = if bisection {
+            (a + b) / cast(2.0)
+        } else {
+            a + ((a - b) * fa / (fb - fa))
+        };
+        let fc = objective(c);
+        // b
+        if fc * fb <= F::zero() {
+            a = b;
+            fa = fb;
+        } else if !bisection {
+            fa = fa / cast(2.0);
+        }
+        // c
+        b = c;
+        fb = fc;
+        // d (while loop)
+    }
+    Ok(a)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate approx;
+    use self::approx::*;
+    use super::*;
+
+    #[test]
+    #[allow(clippy::vec_init_then_push)]
+    fn test_rating_update() {
+        let example_player_rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let mut results = vec![];
+        results.push(GameResult::win(GlickoRating {
+            value: 1400.0,
+            deviation: 30.0,
+        }));
+        results.push(GameResult::loss(GlickoRating {
+            value: 1550.0,
+            deviation: 100.0,
+        }));
+        results.push(GameResult::loss(GlickoRating {
+            value: 1700.0,
+            deviation: 300.0,
+        }));
+
+        let new_rating = new_rating(example_player_rating, &results, 0.5);
+        assert!(Relative::default().epsilon(0.0001).eq(&new_rating.value, &-0.2069));
+        assert!(Relative::default().epsilon(0.0001).eq(&new_rating.deviation, &0.8722));
+        assert!(Relative::default().epsilon(0.0001).eq(&new_rating.volatility, &0.05999))
+    }
+
+    #[test]
+    fn test_rating_update_f32() {
+        let example_player_rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0f32,
+            deviation: 200.0,
+        });
+        let results = vec![
+            GameResult::win(GlickoRating {
+                value: 1400.0,
+                deviation: 30.0,
+            }),
+            GameResult::loss(GlickoRating {
+                value: 1550.0,
+                deviation: 100.0,
+            }),
+            GameResult::loss(GlickoRating {
+                value: 1700.0,
+                deviation: 300.0,
+            }),
+        ];
+
+        let new_rating = new_rating(example_player_rating, &results, 0.5);
+        assert!(Relative::default().epsilon(0.001).eq(&new_rating.value, &-0.2069));
+        assert!(Relative::default().epsilon(0.001).eq(&new_rating.deviation, &0.8722));
+        assert!(Relative::default().epsilon(0.001).eq(&new_rating.volatility, &0.05999))
+    }
+
+    #[test]
+    fn test_update() {
+        let prior_rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let results = [GameResult::win(GlickoRating::unrated())];
+        let mut rating = prior_rating;
+        rating.update(&results, 0.5);
+        assert_eq!(rating, new_rating(prior_rating, &results, 0.5));
+    }
+
+    #[test]
+    fn test_new_rating_single() {
+        let rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let result = GameResult::loss(GlickoRating {
+            value: 1400.0,
+            deviation: 30.0,
+        });
+        assert_eq!(
+            new_rating_single(rating, result, 0.5),
+            new_rating(rating, &[result], 0.5)
+        );
+    }
+
+    #[test]
+    fn test_new_rating_default() {
+        let rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let results = [GameResult::win(GlickoRating::unrated())];
+        assert_eq!(
+            new_rating_default(rating, &results),
+            new_rating(rating, &results, DEFAULT_SYS_CONSTANT)
+        );
+    }
+
+    #[test]
+    fn test_new_rating_with_delta() {
+        let example_player_rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let results = vec![
+            GameResult::win(GlickoRating {
+                value: 1400.0,
+                deviation: 30.0,
+            }),
+            GameResult::loss(GlickoRating {
+                value: 1550.0,
+                deviation: 100.0,
+            }),
+            GameResult::loss(GlickoRating {
+                value: 1700.0,
+                deviation: 300.0,
+            }),
+        ];
+
+        let change = new_rating_with_delta(example_player_rating, &results, 0.5);
+        assert_eq!(change.rating, new_rating(example_player_rating, &results, 0.5));
+        assert!(Relative::default().epsilon(0.0001).eq(&change.value_delta, &-0.2069));
+        assert!(Relative::default().epsilon(0.0001).eq(&change.deviation_delta, &-0.2791));
+        assert!(Relative::default().epsilon(0.1).eq(&change.glicko_value_delta(), &-35.9));
+        assert!(Relative::default().epsilon(0.1).eq(&change.glicko_deviation_delta(), &-48.5));
+    }
+
+    #[test]
+    fn test_rating_period() {
+        let example_player_rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let mut period = RatingPeriod::new();
+        period
+            .record_win(GlickoRating {
+                value: 1400.0,
+                deviation: 30.0,
+            })
+            .record_loss(GlickoRating {
+                value: 1550.0,
+                deviation: 100.0,
+            })
+            .record_loss(GlickoRating {
+                value: 1700.0,
+                deviation: 300.0,
+            });
+        assert_eq!(period.results().len(), 3);
+
+        let new_rating = period.finalize(example_player_rating, 0.5);
+        assert!(Relative::default().epsilon(0.0001).eq(&new_rating.value, &-0.2069));
+        assert!(Relative::default().epsilon(0.0001).eq(&new_rating.deviation, &0.8722));
+        assert!(Relative::default().epsilon(0.0001).eq(&new_rating.volatility, &0.05999))
+    }
+
+    #[test]
+    fn test_new_ratings() {
+        let first = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let second = Glicko2Rating::from(GlickoRating {
+            value: 1700.0,
+            deviation: 100.0,
+        });
+        let first_results = [GameResult::win(second)];
+        let second_results = [GameResult::loss(first)];
+
+        let ratings = new_ratings(&[(first, &first_results), (second, &second_results)], 0.5);
+        assert_eq!(ratings.len(), 2);
+        assert_eq!(ratings[0], new_rating(first, &first_results, 0.5));
+        assert_eq!(ratings[1], new_rating(second, &second_results, 0.5));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_new_ratings() {
+        let first = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let second = Glicko2Rating::from(GlickoRating {
+            value: 1700.0,
+            deviation: 100.0,
+        });
+        let first_results = [GameResult::win(second)];
+        let second_results = [GameResult::loss(first)];
+        let players: [(Glicko2Rating, &[GameResult]); 2] =
+            [(first, &first_results), (second, &second_results)];
+
+        assert_eq!(par_new_ratings(&players, 0.5), new_ratings(&players, 0.5));
+    }
+
+    #[test]
+    fn test_new_rating_with_tolerance() {
+        let example_player_rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let results = vec![
+            GameResult::win(GlickoRating {
+                value: 1400.0,
+                deviation: 30.0,
+            }),
+            GameResult::loss(GlickoRating {
+                value: 1550.0,
+                deviation: 100.0,
+            }),
+            GameResult::loss(GlickoRating {
+                value: 1700.0,
+                deviation: 300.0,
+            }),
+        ];
+
+        let new_rating = new_rating_with_tolerance(example_player_rating, &results, 0.5, 0.001);
+        assert!(Relative::default().epsilon(0.001).eq(&new_rating.value, &-0.2069));
+        assert!(Relative::default().epsilon(0.001).eq(&new_rating.deviation, &0.8722));
+        assert!(Relative::default().epsilon(0.001).eq(&new_rating.volatility, &0.05999))
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_rating_with_non_positive_tolerance() {
+        new_rating_with_tolerance(Glicko2Rating::unrated(), &[], 0.5, 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_rating_with_nan_tolerance() {
+        new_rating_with_tolerance(Glicko2Rating::unrated(), &[], 0.5, f64::NAN);
+    }
+
+    #[test]
+    fn test_try_new_rating_with_config() {
+        let prior = Glicko2Rating::unrated();
+        let results = [GameResult::win(GlickoRating::unrated())];
+        let config = RatingConfig {
+            tolerance: 0.001,
+            ..RatingConfig::new(0.5)
+        };
+        assert_eq!(
+            try_new_rating_with_config(prior, &results, config),
+            Ok(new_rating_with_config(prior, &results, config))
+        );
+        for &tolerance in &[0.0, -1.0, f64::NAN] {
+            let config = RatingConfig {
+                tolerance,
+                ..config
+            };
+            assert_eq!(
+                try_new_rating_with_config(prior, &results, config),
+                Err(Glicko2Error::InvalidTolerance)
+            );
+        }
+    }
+
+    #[test]
+    fn test_new_rating_with_config() {
+        let rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let results = [GameResult::win(GlickoRating::unrated())];
+
+        assert_eq!(
+            new_rating_with_config(rating, &results, RatingConfig::new(0.5)),
+            new_rating(rating, &results, 0.5)
+        );
+
+        let provisional = GlickoRating {
+            value: 1500.0,
+            deviation: 150.0,
+        };
+        let config = RatingConfig {
+            max_opponent_deviation: Some(Glicko2Rating::from(provisional).deviation),
+            ..RatingConfig::new(0.5)
+        };
+        let capped = new_rating_with_config(rating, &results, config);
+        assert_eq!(capped, new_rating(rating, &[GameResult::win(provisional)], 0.5));
+        assert!(capped.value > new_rating(rating, &results, 0.5).value);
+    }
+
+    #[test]
+    fn test_new_rating_instrumented() {
+        let example_player_rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let results = vec![
+            GameResult::win(GlickoRating {
+                value: 1400.0,
+                deviation: 30.0,
+            }),
+            GameResult::loss(GlickoRating {
+                value: 1550.0,
+                deviation: 100.0,
+            }),
+            GameResult::loss(GlickoRating {
+                value: 1700.0,
+                deviation: 300.0,
+            }),
+        ];
+
+        let (rating, stats) = new_rating_instrumented(example_player_rating, &results, 0.5);
+        assert_eq!(rating, new_rating(example_player_rating, &results, 0.5));
+        assert!(!stats.used_shortcut);
+        assert!(stats.iterations > 0);
+
+        let upset = [GameResult::win(Glicko2Rating {
+            value: 3.0,
+            deviation: 0.1,
+            volatility: 0.06,
+        })];
+        let settled_rating = Glicko2Rating {
+            value: 0.0,
+            deviation: 0.3,
+            volatility: 0.06,
+        };
+        let (_, stats) = new_rating_instrumented(settled_rating, &upset, 0.5);
+        assert!(stats.used_shortcut);
+        assert_eq!(stats.bracket_iterations, 0);
+
+        let (_, stats) = new_rating_instrumented(example_player_rating, &[], 0.5);
+        assert_eq!(stats, SolverStats::default());
+    }
+
+    #[test]
+    fn test_f32_small_volatility() {
+        // Near ln(σ²) for a small volatility, adjacent f32 values are further apart than the
+        // convergence tolerance, which used to make the volatility calculation loop forever.
+        let prior = Glicko2Rating::<f32> {
+            value: 0.0,
+            deviation: 1.15,
+            volatility: 3e-4,
+        };
+        let opponent = Glicko2Rating {
+            value: 0.0,
+            deviation: 0.5,
+            volatility: 0.06,
+        };
+        let results = [GameResult::win(opponent), GameResult::loss(opponent)];
+        let (rating, stats) = new_rating_instrumented(prior, &results, 0.5);
+        assert!(rating.is_valid());
+        assert!(stats.iterations < MAX_ITERATIONS);
+        assert!((rating.volatility - 3e-4).abs() < 1e-5);
+        assert!(try_new_rating(prior, &results, 0.5).is_ok());
+    }
+
+    #[test]
+    fn test_bisection() {
+        let prior = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let results = [
+            GameResult::win(GlickoRating {
+                value: 1400.0,
+                deviation: 30.0,
+            }),
+            GameResult::loss(GlickoRating {
+                value: 1550.0,
+                deviation: 100.0,
+            }),
+            GameResult::loss(GlickoRating {
+                value: 1700.0,
+                deviation: 300.0,
+            }),
+        ];
+        let (illinois, stats) = new_rating_instrumented(prior, &results, 0.5);
+        assert!(!stats.used_bisection);
+        let config = RatingConfig {
+            bisection: true,
+            ..RatingConfig::new(0.5)
+        };
+        let bisection = new_rating_with_config(prior, &results, config);
+        assert!(bisection.approx_eq(&illinois, 0.00001));
+
+        // The Illinois algorithm crawls towards the root of a function this flat,
+        // so it falls back to bisection.
+        let mut stats = SolverStats::default();
+        let flat = |x: f64| (x - 0.3).powi(9);
+        let root = find_root(flat, -1.0, 10.0, 0.000001, false, None, &mut stats).unwrap();
+        assert!(stats.used_bisection);
+        assert!(Relative::default().epsilon(0.000001).eq(&root, &0.3));
+    }
+
+    #[test]
+    fn test_rating_delta_sum() {
+        let opponent: GlickoRating = GlickoRating::unrated();
+        let prior_rating = Glicko2Rating::unrated();
+        let mut rating = prior_rating;
+        let mut deltas = Vec::new();
+        for _ in 0..3 {
+            let change = new_rating_with_delta(rating, &[GameResult::win(opponent)], 0.5);
+            rating = change.rating;
+            deltas.push(change.delta());
+        }
+
+        let mut season = RatingDelta::default();
+        for delta in &deltas {
+            season += *delta;
+        }
+        assert_eq!(season, deltas.iter().cloned().sum());
+        assert!(Relative::default()
+            .epsilon(0.0001)
+            .eq(&season.value, &(rating.value - prior_rating.value)));
+        assert!(Relative::default()
+            .epsilon(0.0001)
+            .eq(&season.deviation, &(rating.deviation - prior_rating.deviation)));
+    }
+
+    #[test]
+    fn test_sanitized() {
+        let unrated = Glicko2Rating::unrated();
+        let poisoned = Glicko2Rating {
+            value: f64::NAN,
+            deviation: f64::INFINITY,
+            volatility: f64::NEG_INFINITY,
+        };
+        assert_eq!(poisoned.sanitized(), unrated);
+
+        let out_of_range = Glicko2Rating {
+            value: 1.5,
+            deviation: 0.0,
+            volatility: 50.0,
+        }
+        .sanitized();
+        assert_eq!(out_of_range.value, 1.5);
+        assert!(out_of_range.deviation > 0.0);
+        assert_eq!(out_of_range.volatility, 1.0);
+        let rating = new_rating(out_of_range, &[GameResult::win(unrated)], 0.5);
+        assert!(rating.value.is_finite());
+        assert!(rating.deviation.is_finite());
+        assert!(rating.volatility.is_finite());
+
+        let too_uncertain = Glicko2Rating {
+            deviation: 10.0,
+            ..unrated
+        };
+        assert_eq!(too_uncertain.sanitized(), unrated);
+
+        let valid = Glicko2Rating {
+            value: -0.5,
+            deviation: 0.3,
+            volatility: 0.05,
+        };
+        assert_eq!(valid.sanitized(), valid);
+    }
+
+    #[test]
+    fn test_glicko_volatility_round_trip() {
+        let rating = Glicko2Rating {
+            value: 0.4,
+            deviation: 0.7,
+            volatility: 0.09,
+        };
+        let (glicko, volatility) = rating.to_glicko_preserving();
+        assert_eq!(glicko, GlickoRating::from(rating));
+        assert_eq!(volatility, 0.09);
+
+        let restored = Glicko2Rating::from_glicko(glicko, volatility);
+        assert!(restored.approx_eq(&rating, 0.000001));
+        assert_eq!(restored.volatility, rating.volatility);
+    }
+
+    #[test]
+    fn test_outcome_probabilities() {
+        let a = Glicko2Rating::from(GlickoRating {
+            value: 1700.0,
+            deviation: 80.0,
+        });
+        let b = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 120.0,
+        });
+        let expected_score = win_probability(a, b);
+
+        let (win, draw, loss) = outcome_probabilities(a, b);
+        assert_eq!(draw, 0.0);
+        assert!(Relative::default().epsilon(0.000001).eq(&win, &expected_score));
+        assert!(Relative::default().epsilon(0.000001).eq(&(win + loss), &1.0));
+
+        let (win, draw, loss) = outcome_probabilities_with_draw_rate(a, b, 0.4);
+        assert!(draw > 0.0 && draw < 0.4);
+        assert!(win > 0.0 && loss > 0.0);
+        assert!(Relative::default().epsilon(0.000001).eq(&(win + draw + loss), &1.0));
+        assert!(Relative::default()
+            .epsilon(0.000001)
+            .eq(&(win + draw / 2.0), &expected_score));
+
+        let (win, draw, loss) = outcome_probabilities_with_draw_rate(a, a, 0.4);
+        assert!(Relative::default().epsilon(0.000001).eq(&draw, &0.4));
+        assert!(Relative::default().epsilon(0.000001).eq(&win, &0.3));
+        assert!(Relative::default().epsilon(0.000001).eq(&loss, &0.3));
+    }
+
+    #[test]
+    fn test_outcome_probabilities_with_model() {
+        let a = Glicko2Rating::from(GlickoRating {
+            value: 1700.0,
+            deviation: 80.0,
+        });
+        let b = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 120.0,
+        });
+        assert_eq!(
+            outcome_probabilities_with_model(a, b, DrawModel::default()),
+            outcome_probabilities(a, b)
+        );
+        assert_eq!(
+            outcome_probabilities_with_model(a, b, DrawModel::DrawRate(0.4)),
+            outcome_probabilities_with_draw_rate(a, b, 0.4)
+        );
+
+        // ν = 2d / (1 - d) gives a draw rate of d between evenly matched players.
+        let (win, draw, loss) = outcome_probabilities_with_model(a, a, DrawModel::Davidson(0.5));
+        assert!(Relative::default().epsilon(0.000001).eq(&draw, &0.2));
+        assert!(Relative::default().epsilon(0.000001).eq(&win, &0.4));
+        assert!(Relative::default().epsilon(0.000001).eq(&loss, &0.4));
+
+        let p = win_probability(a, b);
+        let (win, draw, loss) = outcome_probabilities_with_model(a, b, DrawModel::Davidson(0.5));
+        assert!(draw > 0.0 && draw < 0.2);
+        assert!(Relative::default().epsilon(0.000001).eq(&(win + draw + loss), &1.0));
+        assert!(Relative::default().epsilon(0.000001).eq(&(win / loss), &(p / (1.0 - p))));
+        assert_eq!(
+            outcome_probabilities_with_model(a, b, DrawModel::Davidson(0.0)),
+            (p, 0.0, 1.0 - p)
+        );
+    }
+
+    #[test]
+    fn test_new_rating_with_floor() {
+        let prior_rating = Glicko2Rating::from(GlickoRating {
+            value: 1210.0,
+            deviation: 100.0,
+        });
+        let opponent: GlickoRating = GlickoRating::unrated();
+        let results = [GameResult::loss(opponent), GameResult::loss(opponent)];
+
+        let unfloored = new_rating(prior_rating, &results, 0.5);
+        assert!(GlickoRating::from(unfloored).value < 1200.0);
+
+        let floored = new_rating_with_floor(prior_rating, &results, 0.5, 1200.0);
+        assert!(Relative::default()
+            .epsilon(0.000001)
+            .eq(&GlickoRating::from(floored).value, &1200.0));
+        assert_eq!(floored.deviation, unfloored.deviation);
+        assert_eq!(floored.volatility, unfloored.volatility);
+
+        let above_floor = new_rating_with_floor(prior_rating, &results, 0.5, 1000.0);
+        assert_eq!(above_floor, unfloored);
+    }
+
+    #[test]
+    fn test_new_rating_iter() {
+        let example_player_rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let opponents = [(1400.0, 30.0, 1.0), (1550.0, 100.0, 0.0), (1700.0, 300.0, 0.0)];
+        let results = opponents.iter().map(|&(value, deviation, score)| {
+            GameResult::new(GlickoRating { value, deviation }, score)
+        });
+        let collected: Vec<GameResult> = results.clone().collect();
+
+        assert_eq!(
+            new_rating_iter(example_player_rating, results, 0.5),
+            new_rating(example_player_rating, &collected, 0.5)
+        );
+    }
+
+    #[test]
+    fn test_const_new() {
+        const MASTER: GlickoRating = GlickoRating::new(2200.0, 50.0);
+        static REFERENCE: [Glicko2Rating; 2] = [
+            Glicko2Rating::new(0.0, 2.0148, 0.06),
+            Glicko2Rating::new(1.5, 0.3, 0.05),
+        ];
+        assert_eq!(
+            MASTER,
+            GlickoRating {
+                value: 2200.0,
+                deviation: 50.0,
+            }
+        );
+        assert_eq!(REFERENCE[1], Glicko2Rating::try_new(1.5, 0.3, 0.05).unwrap());
+    }
+
+    #[test]
+    fn test_games_to_deviation() {
+        let opponent = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 50.0,
+        });
+        let unrated = Glicko2Rating::unrated();
+        let target = PROVISIONAL_DEVIATION / 173.7178;
+
+        let games = games_to_deviation(unrated, target, opponent, 0.5).unwrap();
+        assert!(games > 0);
+        let mut rating = unrated;
+        for _ in 0..games {
+            rating = new_rating(rating, &[GameResult::draw(opponent)], 0.5);
+        }
+        assert!(rating.deviation <= target);
+
+        assert_eq!(games_to_deviation(unrated, unrated.deviation, opponent, 0.5), Some(0));
+        assert_eq!(games_to_deviation(unrated, 0.0, opponent, 0.5), None);
+    }
+
+    #[test]
+    fn test_certain_opponent() {
+        let example_player_rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let certain_opponent = GlickoRating {
+            value: 1400.0,
+            deviation: 0.0,
+        };
+        let nearly_certain_opponent = GlickoRating {
+            value: 1400.0,
+            deviation: 1e-12,
+        };
+
+        let rating = new_rating(example_player_rating, &[GameResult::win(certain_opponent)], 0.5);
+        let nearly = new_rating(
+            example_player_rating,
+            &[GameResult::win(nearly_certain_opponent)],
+            0.5,
+        );
+        assert!(rating.value.is_finite());
+        assert!(rating.deviation.is_finite() && rating.deviation > 0.0);
+        assert!(rating.volatility.is_finite() && rating.volatility > 0.0);
+        assert!(rating.approx_eq(&nearly, 1e-9));
+        let glicko = GlickoRating::from(rating);
+        assert!(Relative::default().epsilon(0.1).eq(&glicko.value, &1563.6));
+        assert!(Relative::default().epsilon(0.1).eq(&glicko.deviation, &175.2));
+    }
+
+    #[test]
+    fn test_extreme_rating_gap() {
+        let impossible_opponent = Glicko2Rating {
+            value: 1e6,
+            deviation: 0.0,
+            volatility: 0.06,
+        };
+        let rating = new_rating(
+            Glicko2Rating::unrated(),
+            &[GameResult::win(impossible_opponent)],
+            0.5,
+        );
+        assert!(rating.value.is_finite());
+        assert!(rating.deviation.is_finite());
+        assert!(rating.volatility.is_finite());
+    }
+
+    #[test]
+    fn test_from_glicko() {
+        let stored = GlickoRating {
+            value: 1850.0,
+            deviation: 75.0,
+        };
+        let rating = Glicko2Rating::from_glicko(stored, 0.05);
+        let assumed = Glicko2Rating::from(stored);
+        assert_eq!(rating.value, assumed.value);
+        assert_eq!(rating.deviation, assumed.deviation);
+        assert_eq!(rating.volatility, 0.05);
+    }
+
+    #[test]
+    fn test_compute_v_and_delta() {
+        let example_player_rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let results = vec![
+            GameResult::win(GlickoRating {
+                value: 1400.0,
+                deviation: 30.0,
+            }),
+            GameResult::loss(GlickoRating {
+                value: 1550.0,
+                deviation: 100.0,
+            }),
+            GameResult::loss(GlickoRating {
+                value: 1700.0,
+                deviation: 300.0,
+            }),
+        ];
+
+        // Values from the example in the glicko2 paper
+        let v = compute_v(example_player_rating, &results);
+        let delta = compute_delta(example_player_rating, &results);
+        assert!(Relative::default().epsilon(0.001).eq(&v, &1.7785));
+        assert!(Relative::default().epsilon(0.001).eq(&delta, &-0.4834));
+    }
+
+    #[test]
+    fn test_checked_conversions() {
+        let glicko = GlickoRating {
+            value: 1650.0,
+            deviation: 80.0,
+        };
+        assert_eq!(Glicko2Rating::try_from_glicko(glicko), Ok(Glicko2Rating::from(glicko)));
+        let glicko2 = Glicko2Rating::from(glicko);
+        assert_eq!(GlickoRating::try_from_glicko2(glicko2), Ok(GlickoRating::from(glicko2)));
+
+        let nan_value = GlickoRating {
+            value: f64::NAN,
+            deviation: 80.0,
+        };
+        assert_eq!(
+            Glicko2Rating::try_from_glicko(nan_value),
+            Err(Glicko2Error::NonFinite { field: "value" })
+        );
+        let negative_deviation = GlickoRating {
+            value: 1650.0,
+            deviation: -1.0,
+        };
+        assert_eq!(
+            Glicko2Rating::try_from_glicko(negative_deviation),
+            Err(Glicko2Error::InvalidDeviation)
+        );
+        let zero_volatility = Glicko2Rating {
+            volatility: 0.0,
+            ..glicko2
+        };
+        assert_eq!(
+            GlickoRating::try_from_glicko2(zero_volatility),
+            Err(Glicko2Error::InvalidVolatility)
+        );
+
+        // Zero deviations are valid ratings, but not valid for conversion.
+        let zero_deviation = GlickoRating {
+            value: 1650.0,
+            deviation: 0.0,
+        };
+        assert!(GlickoRating::try_new(zero_deviation.value, 0.0).is_ok());
+        assert_eq!(
+            Glicko2Rating::try_from_glicko(zero_deviation),
+            Err(Glicko2Error::InvalidDeviation)
+        );
+        let zero_deviation = Glicko2Rating {
+            deviation: 0.0,
+            ..glicko2
+        };
+        assert!(Glicko2Rating::try_new(0.0, 0.0, 0.06).is_ok());
+        assert_eq!(
+            GlickoRating::try_from_glicko2(zero_deviation),
+            Err(Glicko2Error::InvalidDeviation)
+        );
+    }
+
+    #[test]
+    fn test_score() {
+        assert_eq!(Score::custom(0.75).map(Score::value), Ok(0.75));
+        assert_eq!(Score::custom(1.0), Ok(Score::WIN));
+        assert_eq!(Score::custom(1.5), Err(Glicko2Error::InvalidScore));
+        assert_eq!(Score::custom(-0.1), Err(Glicko2Error::InvalidScore));
+        assert_eq!(Score::custom(f64::NAN), Err(Glicko2Error::InvalidScore));
+        assert_eq!(Score::from(Outcome::Draw), Score::DRAW);
+        assert_eq!(Score::<f32>::from(Outcome::Loss).value(), 0.0f32);
+
+        let opponent: GlickoRating = GlickoRating::unrated();
+        assert_eq!(
+            GameResult::with_score(opponent, Score::WIN).score(),
+            GameResult::win(opponent).score()
+        );
+        assert_eq!(GameResult::with_score(opponent, Score::LOSS).score(), 0.0);
+    }
+
+    #[test]
+    fn test_conservative() {
+        let established = GlickoRating {
+            value: 1800.0,
+            deviation: 50.0,
+        };
+        let newcomer = GlickoRating {
+            value: 1850.0,
+            deviation: 300.0,
+        };
+        assert_eq!(established.conservative(2.0), 1700.0);
+        assert_eq!(established.conservative(1.96), established.confidence_interval(1.96).0);
+        assert!(newcomer.conservative(3.0) < established.conservative(3.0));
+        assert_eq!(newcomer.conservative(0.0), newcomer.value);
+    }
+
+    #[test]
+    fn test_new_rating_capped() {
+        let prior: Glicko2Rating = Glicko2Rating::unrated();
+        let opponent = GlickoRating {
+            value: 1500.0,
+            deviation: 50.0,
+        };
+        let few = [GameResult::win(opponent), GameResult::loss(opponent)];
+        assert_eq!(
+            new_rating_capped(prior, &few, 0.5, 5.0),
+            new_rating(prior, &few, 0.5)
+        );
+
+        // Twenty wins capped at five count as much as five wins.
+        let many = [GameResult::win(opponent); 20];
+        let capped = new_rating_capped(prior, &many, 0.5, 5.0);
+        let five = new_rating(prior, &many[..5], 0.5);
+        assert!(capped.approx_eq(&five, 0.000001));
+        assert!(capped.value < new_rating(prior, &many, 0.5).value);
+
+        // A cap of no games counts as a period without games.
+        let inactive = new_rating(prior, &[], 0.5);
+        assert_eq!(new_rating_capped(prior, &many, 0.5, 0.0), inactive);
+        assert_eq!(new_rating_capped(prior, &many, 0.5, -1.0), inactive);
+        assert_eq!(new_rating_capped(prior, &many, 0.5, f64::NAN), inactive);
+    }
+
+    #[test]
+    fn test_new_rating_vs() {
+        let prior = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let bot = Glicko2Rating::from(GlickoRating {
+            value: 1800.0,
+            deviation: 50.0,
+        });
+        let mut results = vec![GameResult::win(bot); 7];
+        results.push(GameResult::draw(bot));
+        results.extend_from_slice(&[GameResult::loss(bot); 2]);
+        let expected = new_rating(prior, &results, 0.5);
+        assert!(new_rating_vs(prior, bot, 7, 1, 2, 0.5).approx_eq(&expected, 0.000001));
+        assert_eq!(new_rating_vs(prior, bot, 0, 0, 0, 0.5), apply_inactivity(prior));
+    }
+
+    #[test]
+    fn test_new_rating_clamped_change() {
+        let prior = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 300.0,
+        });
+        let opponent = GlickoRating {
+            value: 1900.0,
+            deviation: 50.0,
+        };
+        let wins = [GameResult::win(opponent); 3];
+        let unclamped = new_rating(prior, &wins, 0.5);
+        assert!(GlickoRating::from(unclamped).value > 1600.0);
+
+        let clamped = new_rating_clamped_change(prior, &wins, 0.5, 100.0);
+        assert!(Relative::default()
+            .epsilon(0.000001)
+            .eq(&GlickoRating::from(clamped).value, &1600.0));
+        assert_eq!(clamped.deviation, unclamped.deviation);
+        assert_eq!(clamped.volatility, unclamped.volatility);
+
+        let losses = [GameResult::loss(opponent); 3];
+        let clamped = new_rating_clamped_change(prior, &losses, 0.5, 10.0);
+        assert!(Relative::default()
+            .epsilon(0.000001)
+            .eq(&GlickoRating::from(clamped).value, &1490.0));
+
+        assert_eq!(
+            new_rating_clamped_change(prior, &wins, 0.5, 10_000.0),
+            unclamped
+        );
+
+        // A negative or NaN limit allows no change in value.
+        for &limit in &[-10.0, f64::NAN] {
+            let clamped = new_rating_clamped_change(prior, &wins, 0.5, limit);
+            assert_eq!(clamped.value, prior.value);
+            assert_eq!(clamped.deviation, unclamped.deviation);
+        }
+    }
+
+    #[test]
+    fn test_marginal_gain() {
+        let prior = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let opponent = |value, deviation| Glicko2Rating::from(GlickoRating { value, deviation });
+        let gain = marginal_gain(prior, opponent(1400.0, 30.0), 0.5);
+        let rating = new_rating_single(prior, GameResult::win(opponent(1400.0, 30.0)), 0.5);
+        assert!(Relative::default().epsilon(0.000001).eq(
+            &gain,
+            &(GlickoRating::from(rating).value - GlickoRating::from(prior).value)
+        ));
+        assert!(gain > 0.0);
+        assert!(marginal_gain(prior, opponent(1700.0, 30.0), 0.5) > gain);
+        assert!(marginal_gain(prior, opponent(1400.0, 300.0), 0.5) < gain);
+    }
+
+    #[test]
+    fn test_rating_history() {
+        let opponent: GlickoRating = GlickoRating::unrated();
+        let periods = vec![
+            vec![GameResult::win(opponent), GameResult::draw(opponent)],
+            vec![],
+            vec![GameResult::loss(opponent)],
+        ];
+        let start = Glicko2Rating::unrated();
+
+        let history = rating_history(start, &periods, 0.5);
+        assert_eq!(history.len(), 3);
+        let mut rating = start;
+        for (results, historic_rating) in periods.iter().zip(&history) {
+            rating = new_rating(rating, results, 0.5);
+            assert_eq!(*historic_rating, rating);
+        }
+        assert!(rating_history(start, &[], 0.5).is_empty());
+    }
+
+    #[test]
+    fn test_from_scored() {
+        let opponent: GlickoRating = GlickoRating::unrated();
+        let results =
+            GameResult::from_scored(vec![(opponent, 1.0), (opponent, 2.0 / 3.0)]).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].score(), 1.0);
+        assert_eq!(results[1].score(), 2.0 / 3.0);
+        assert_eq!(results[1].opponent_value(), Glicko2Rating::from(opponent).value);
+
+        assert_eq!(
+            GameResult::from_scored(vec![(opponent, 0.5), (opponent, 1.5)]).unwrap_err(),
+            Glicko2Error::InvalidScore
+        );
+    }
+
+    #[test]
+    fn test_from_records() {
+        use std::collections::HashMap;
+
+        let strong = Glicko2Rating {
+            value: 1.0,
+            deviation: 0.5,
+            volatility: 0.06,
+        };
+        let weak = Glicko2Rating {
+            value: -1.0,
+            ..strong
+        };
+        let mut records = HashMap::new();
+        records.insert(HashableRating(strong), (1, 0, 2));
+        records.insert(HashableRating(weak), (3, 1, 0));
+        let results = GameResult::from_records(records);
+        assert_eq!(results.len(), 7);
+        let scores = |opponent: Glicko2Rating| -> Vec<f64> {
+            results
+                .iter()
+                .filter(|result| result.opponent_value() == opponent.value)
+                .map(GameResult::score)
+                .collect()
+        };
+        assert_eq!(scores(strong), vec![1.0, 0.0, 0.0]);
+        assert_eq!(scores(weak), vec![1.0, 1.0, 1.0, 0.5]);
+
+        let unrated: GlickoRating = GlickoRating::unrated();
+        assert!(GameResult::from_records(vec![(unrated, (0, 0, 0))]).is_empty());
+    }
+
+    #[test]
+    fn test_stack_results() {
+        let example_player_rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let results: [GameResult; 3] = [
+            GameResult::win(GlickoRating {
+                value: 1400.0,
+                deviation: 30.0,
+            }),
+            GameResult::loss(GlickoRating {
+                value: 1550.0,
+                deviation: 100.0,
+            }),
+            GameResult::loss(GlickoRating {
+                value: 1700.0,
+                deviation: 300.0,
+            }),
+        ];
+
+        let new_rating: GlickoRating = new_rating(example_player_rating, &results, 0.5).into();
+        assert!(Relative::default().epsilon(0.1).eq(&new_rating.value, &1464.06));
+        assert!(Relative::default().epsilon(0.1).eq(&new_rating.deviation, &151.52));
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn test_fixed_rating_period() {
+        let example_player_rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let first = GlickoRating {
+            value: 1400.0,
+            deviation: 30.0,
+        };
+        let second = GlickoRating {
+            value: 1550.0,
+            deviation: 100.0,
+        };
+
+        let mut period: FixedRatingPeriod<f64, 2> = FixedRatingPeriod::new();
+        period.record_win(first).unwrap().record_loss(second).unwrap();
+        let overflow = period.record_draw(second).unwrap_err();
+        assert_eq!(overflow.score(), 0.5);
+        assert_eq!(period.results().len(), 2);
+
+        let expected = new_rating(
+            example_player_rating,
+            &[GameResult::win(first), GameResult::loss(second)],
+            0.5,
+        );
+        assert_eq!(period.finalize(example_player_rating, 0.5), expected);
+    }
+
+    #[test]
+    fn test_zero_deviation_prior() {
+        let certain_rating = Glicko2Rating {
+            value: 0.5,
+            deviation: 0.0,
+            volatility: 0.06,
+        };
+        let results = [
+            GameResult::win(Glicko2Rating::unrated()),
+            GameResult::loss(Glicko2Rating::unrated()),
+        ];
+
+        let rating = new_rating(certain_rating, &results, 0.5);
+        assert!(rating.value.is_finite());
+        assert!(rating.deviation.is_finite() && rating.deviation > 0.0);
+        assert!(rating.volatility.is_finite() && rating.volatility > 0.0);
+        assert!(rating.deviation <= certain_rating.volatility);
+        assert!(Relative::default().epsilon(0.01).eq(&rating.value, &certain_rating.value));
+
+        let floored = Glicko2Rating {
+            deviation: 0.000001,
+            ..certain_rating
+        };
+        assert_eq!(rating, new_rating(floored, &results, 0.5));
+        let inactive = new_rating(certain_rating, &[], 0.5);
+        assert!(Relative::default().epsilon(0.000001).eq(&inactive.deviation, &0.06));
+    }
+
+    #[test]
+    fn test_new_rating_dual() {
+        let prior_rating = Glicko2Rating::unrated();
+        let results = [GameResult::win(GlickoRating {
+            value: 1400.0,
+            deviation: 30.0,
+        })];
+
+        let (rating, display) = new_rating_dual(prior_rating, &results, 0.5);
+        assert_eq!(rating, new_rating(prior_rating, &results, 0.5));
+        assert_eq!(display, GlickoRating::from(rating));
+    }
+
+    #[test]
+    fn test_log_loss() {
+        assert!(Relative::default().epsilon(0.000001).eq(&log_loss(0.5, 1.0), &2f64.ln()));
+        assert!(Relative::default().epsilon(0.000001).eq(&log_loss(0.8, 0.0), &-0.2f64.ln()));
+        assert!(log_loss(0.9, 1.0) < log_loss(0.6, 1.0));
+        assert!(log_loss(0.0, 1.0).is_finite());
+        assert!(log_loss(1.0f32, 0.0).is_finite());
+
+        let predictions = [(0.5, 1.0), (0.8, 0.0)];
+        let expected = (log_loss(0.5, 1.0) + log_loss(0.8, 0.0)) / 2.0;
+        assert!(Relative::default().epsilon(0.000001).eq(&mean_log_loss(&predictions), &expected));
+        assert!(mean_log_loss::<f64>(&[]).is_nan());
+    }
+
+    #[test]
+    fn test_combine() {
+        let a = Glicko2Rating {
+            value: 1.0,
+            deviation: 0.5,
+            volatility: 0.06,
+        };
+        let b = Glicko2Rating {
+            value: -0.5,
+            deviation: 1.0,
+            volatility: 0.08,
+        };
+
+        let combined = combine(a, b);
+        // The precisions are 4 and 1, so `a` has 4 times the weight of `b`
+        assert!(Relative::default().epsilon(0.000001).eq(&combined.value, &0.7));
+        assert!(Relative::default()
+            .epsilon(0.000001)
+            .eq(&combined.deviation, &(1.0 / 5f64.sqrt())));
+        assert!(Relative::default().epsilon(0.000001).eq(&combined.volatility, &0.064));
+        assert!(combined.approx_eq(&combine(b, a), 0.000001));
+
+        let same = combine(a, a);
+        assert!(Relative::default().epsilon(0.000001).eq(&same.value, &a.value));
+        assert!(same.deviation < a.deviation);
+    }
+
+    #[test]
+    fn test_volatility_bounds() {
+        let example_player_rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 50.0,
+        });
+        let strong_opponent = GlickoRating {
+            value: 2100.0,
+            deviation: 30.0,
+        };
+        let upset = [GameResult::win(strong_opponent); 10];
+        let unbounded = new_rating(example_player_rating, &upset, 1.2);
+        assert!(unbounded.volatility > 0.065);
+
+        let config = RatingConfig {
+            max_volatility: Some(0.065),
+            ..RatingConfig::new(1.2)
+        };
+        let bounded = new_rating_with_config(example_player_rating, &upset, config);
+        assert_eq!(bounded.volatility, 0.065);
+        assert!(bounded.deviation < unbounded.deviation);
+
+        let config = RatingConfig {
+            min_volatility: Some(0.1),
+            ..RatingConfig::new(0.5)
+        };
+        let floored = new_rating_with_config(example_player_rating, &upset, config);
+        assert_eq!(floored.volatility, 0.1);
+        assert_eq!(
+            new_rating_with_config(example_player_rating, &upset, RatingConfig::new(1.2)),
+            unbounded
+        );
+    }
+
+    #[test]
+    fn test_batch_conversions() {
+        let glicko = [
+            GlickoRating {
+                value: 1700.0,
+                deviation: 80.0,
+            },
+            GlickoRating::unrated(),
+        ];
+        let glicko2 = to_glicko2_all(&glicko);
+        assert_eq!(glicko2, vec![Glicko2Rating::from(glicko[0]), Glicko2Rating::unrated()]);
+
+        let round_trip = to_glicko_all(&glicko2);
+        assert_eq!(round_trip.len(), 2);
+        for (original, converted) in glicko.iter().zip(&round_trip) {
+            assert!(original.approx_eq(converted, 0.000001));
+        }
+        assert!(to_glicko_all::<f64>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_expected_score_matrix() {
+        let ratings = to_glicko2_all(&[
+            GlickoRating {
+                value: 1800.0,
+                deviation: 60.0,
+            },
+            GlickoRating {
+                value: 1500.0,
+                deviation: 200.0,
+            },
+            GlickoRating::unrated(),
+        ]);
+
+        let matrix = expected_score_matrix(&ratings);
+        assert_eq!(matrix.len(), 3);
+        for i in 0..3 {
+            assert_eq!(matrix[i].len(), 3);
+            assert!(Relative::default().epsilon(0.000001).eq(&matrix[i][i], &0.5));
+            for j in 0..3 {
+                assert!(Relative::default()
+                    .epsilon(0.000001)
+                    .eq(&(matrix[i][j] + matrix[j][i]), &1.0));
+                assert_eq!(matrix[i][j], win_probability(ratings[i], ratings[j]));
+            }
+        }
+        assert!(matrix[0][1] > 0.5);
+    }
+
+    #[test]
+    fn test_seed() {
+        let rating = Glicko2Rating::seed(1850.0, 120.0, 0.06);
+        assert_eq!(rating.volatility, 0.06);
+        let glicko = GlickoRating::from(rating);
+        assert!(Relative::default().epsilon(0.000001).eq(&glicko.value, &1850.0));
+        assert!(Relative::default().epsilon(0.000001).eq(&glicko.deviation, &120.0));
+    }
+
+    #[test]
+    fn test_population_percentile() {
+        let rating = |value| GlickoRating {
+            value,
+            deviation: 100.0,
+        };
+        let percentile = |value| rating(value).population_percentile(1500.0, 300.0);
+        assert!(Relative::default().epsilon(0.0000002).eq(&percentile(1500.0), &0.5));
+        assert!(Relative::default().epsilon(0.0000002).eq(&percentile(1800.0), &0.8413447));
+        assert!(Relative::default().epsilon(0.0000002).eq(&percentile(1200.0), &0.1586553));
+        assert!(Relative::default().epsilon(0.0000002).eq(&percentile(2100.0), &0.9772499));
+        assert!(percentile(4000.0) <= 1.0);
+        assert!(percentile(-1000.0) >= 0.0);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_timed_rating() {
+        use chrono::TimeZone;
+
+        let rating = Glicko2Rating {
+            value: 0.0,
+            deviation: 0.5,
+            volatility: 0.06,
+        };
+        let earlier = TimedRating {
+            rating,
+            at: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+        };
+        let later = TimedRating {
+            rating,
+            at: Utc.with_ymd_and_hms(2024, 1, 18, 12, 0, 0).unwrap(),
+        };
+        let week = TimeDelta::days(7);
+        assert_eq!(later.periods_since(&earlier, week), Some(2.5));
+        assert_eq!(earlier.periods_since(&later, week), Some(-2.5));
+
+        assert_eq!(
+            earlier.deviation_at(later.at, week),
+            Some(deviation_after_idle(rating, 17.5, 7.0))
+        );
+        assert_eq!(later.deviation_at(earlier.at, week), Some(rating.deviation));
+
+        for &period_length in &[TimeDelta::zero(), -week, TimeDelta::microseconds(10)] {
+            assert_eq!(later.periods_since(&earlier, period_length), None);
+            assert_eq!(earlier.deviation_at(later.at, period_length), None);
+        }
+    }
+
+    #[test]
+    fn test_average_opponent() {
+        assert_eq!(average_opponent::<f64>(&[]), None);
+        let opponent = GlickoRating {
+            value: 1700.0,
+            deviation: 80.0,
+        };
+        assert_eq!(average_opponent(&[GameResult::weighted(opponent, 1.0, 0.0)]), None);
+
+        let average = average_opponent(&[GameResult::win(opponent), GameResult::loss(opponent)]);
+        assert!(average.unwrap().approx_eq(&opponent, 0.000001));
+
+        // An uncertain opponent counts for less than a certain one.
+        let certain = GlickoRating {
+            value: 1400.0,
+            deviation: 0.0,
+        };
+        let uncertain = GlickoRating {
+            value: 1600.0,
+            deviation: 350.0,
+        };
+        let average =
+            average_opponent(&[GameResult::win(certain), GameResult::win(uncertain)]).unwrap();
+        assert!(average.value > 1400.0 && average.value < 1500.0);
+        assert!(average.deviation > 0.0 && average.deviation < 350.0);
+    }
+
+    #[test]
+    fn test_apply_volatility_decay() {
+        let rating = Glicko2Rating {
+            value: 0.5,
+            deviation: 0.4,
+            volatility: 0.2,
+        };
+        let decayed = apply_volatility_decay(rating, 0.06, 0.5);
+        assert!(Relative::default().epsilon(0.000001).eq(&decayed.volatility, &0.13));
+        assert_eq!(decayed.value, rating.value);
+        assert_eq!(decayed.deviation, rating.deviation);
+
+        assert_eq!(apply_volatility_decay(rating, 0.06, 0.0), rating);
+        assert_eq!(apply_volatility_decay(rating, 0.06, 1.0).volatility, 0.06);
+        assert_eq!(apply_volatility_decay(rating, 0.06, 2.0).volatility, 0.06);
+    }
+
+    #[test]
+    fn test_effective_games() {
+        let unrated: GlickoRating = GlickoRating::unrated();
+        assert_eq!(effective_games(&unrated), 0.0);
+
+        // Ten evenly matched games against a certain opponent in one rating period
+        // should be worth ten games.
+        let opponent = Glicko2Rating {
+            value: 0.0,
+            deviation: 0.0,
+            volatility: 0.06,
+        };
+        let results = [GameResult::draw(opponent); 10];
+        let prior = Glicko2Rating {
+            volatility: 0.0,
+            ..Glicko2Rating::unrated()
+        };
+        let config = RatingConfig {
+            fixed_volatility: true,
+            ..RatingConfig::default()
+        };
+        let rating = new_rating_with_config(prior, &results, config);
+        let games = effective_games(&GlickoRating::from(rating));
+        assert!(Relative::default().epsilon(0.000001).eq(&games, &10.0));
+
+        let established = GlickoRating {
+            value: 1500.0,
+            deviation: 50.0,
+        };
+        assert!(effective_games(&established) > games);
+    }
+
+    #[test]
+    fn test_is_valid() {
+        let prior: Glicko2Rating = Glicko2Rating::unrated();
+        assert!(prior.is_valid());
+        let results = [
+            GameResult::win(GlickoRating {
+                value: 1400.0,
+                deviation: 30.0,
+            }),
+            GameResult::draw(Glicko2Rating {
+                value: 40.0,
+                deviation: 0.0,
+                volatility: 0.06,
+            }),
+        ];
+        let rating = new_rating(prior, &results, 0.5);
+        assert!(rating.is_valid());
+        assert!(GlickoRating::from(rating).is_valid());
+
+        assert!(!Glicko2Rating::new(f64::NAN, 1.0, 0.06).is_valid());
+        assert!(!Glicko2Rating::new(0.0, -1.0, 0.06).is_valid());
+        assert!(!Glicko2Rating::new(0.0, 1.0, 0.0).is_valid());
+        assert!(!GlickoRating::new(1500.0, f64::INFINITY).is_valid());
+    }
+
+    #[test]
+    fn test_new_rating_fixed_volatility() {
+        let prior = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let results = [
+            GameResult::win(GlickoRating {
+                value: 1400.0,
+                deviation: 30.0,
+            }),
+            GameResult::loss(GlickoRating {
+                value: 1550.0,
+                deviation: 100.0,
+            }),
+            GameResult::loss(GlickoRating {
+                value: 1700.0,
+                deviation: 300.0,
+            }),
+        ];
+        let rating = new_rating_fixed_volatility(prior, &results);
+        assert_eq!(rating.volatility, prior.volatility);
+        // The paper's example only changes the volatility from 0.06 to 0.05999,
+        // so the value and deviation barely differ from it.
+        let glicko = GlickoRating::from(rating);
+        assert!(Relative::default().epsilon(0.01).eq(&glicko.value, &1464.06));
+        assert!(Relative::default().epsilon(0.01).eq(&glicko.deviation, &151.52));
+
+        // Wild results would otherwise raise the volatility.
+        let upset = [GameResult::win(GlickoRating {
+            value: 2500.0,
+            deviation: 30.0,
+        })];
+        let settled = Glicko2Rating {
+            deviation: 0.3,
+            ..prior
+        };
+        assert!(new_rating(settled, &upset, 1.2).volatility > settled.volatility);
+        assert_eq!(
+            new_rating_fixed_volatility(settled, &upset).volatility,
+            settled.volatility
+        );
+    }
+
+    #[test]
+    fn test_compensated_summation() {
+        let example_player_rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let mut results: Vec<GameResult> = (0..50)
+            .map(|i| {
+                let i = i as f64;
+                let opponent = GlickoRating {
+                    value: 1200.0 + 13.7 * i,
+                    deviation: 30.0 + 5.3 * i,
+                };
+                GameResult::new(opponent, (i % 3.0) / 2.0)
+            })
+            .collect();
+        let config = RatingConfig {
+            compensated_summation: true,
+            ..RatingConfig::new(0.5)
+        };
+
+        let forward = new_rating_with_config(example_player_rating, &results, config);
+        let plain = new_rating(example_player_rating, &results, 0.5);
+        assert!(forward.approx_eq(&plain, 0.000001));
+        results.reverse();
+        let reversed = new_rating_with_config(example_player_rating, &results, config);
+        results.swap(3, 41);
+        let shuffled = new_rating_with_config(example_player_rating, &results, config);
+        assert_eq!(forward, reversed);
+        assert_eq!(forward, shuffled);
+    }
+
+    #[test]
+    fn test_confidence() {
+        let rating = |deviation| GlickoRating {
+            value: 1500.0,
+            deviation,
+        };
+        assert_eq!(GlickoRating::<f64>::unrated().confidence(), 0.0);
+        assert!(Relative::default().epsilon(0.0001).eq(&rating(50.0).confidence(), &0.9796));
+        assert!(Relative::default().epsilon(0.0001).eq(&rating(110.0).confidence(), &0.9012));
+        assert!(rating(50.0).confidence() > rating(110.0).confidence());
+        assert_eq!(rating(0.0).confidence(), 1.0);
+        assert_eq!(rating(500.0).confidence(), 0.0);
+    }
+
+    #[test]
+    fn test_from_bool_and_ordering() {
+        let opponent: GlickoRating = GlickoRating::unrated();
+        assert_eq!(GameResult::from_bool(opponent, true).score(), 1.0);
+        assert_eq!(GameResult::from_bool(opponent, false).score(), 0.0);
+
+        let my_points = 21;
+        assert_eq!(GameResult::from_ordering(opponent, my_points.cmp(&15)).score(), 1.0);
+        assert_eq!(GameResult::from_ordering(opponent, my_points.cmp(&21)).score(), 0.5);
+        assert_eq!(GameResult::from_ordering(opponent, my_points.cmp(&25)).score(), 0.0);
+    }
+
+    #[test]
+    fn test_performance_rating() {
+        let opponent = |value| GlickoRating {
+            value,
+            deviation: 50.0,
+        };
+        // Scoring 50% against an evenly spread field performs at the middle of the field
+        let results = [
+            GameResult::win(opponent(1400.0)),
+            GameResult::draw(opponent(1500.0)),
+            GameResult::loss(opponent(1600.0)),
+        ];
+        let performance = performance_rating(&results).unwrap();
+        assert!(Relative::default().epsilon(0.01).eq(&performance.value, &1500.0));
+        assert!(performance.deviation > 0.0 && performance.deviation < 350.0);
+
+        let glicko2 = Glicko2Rating::from(performance);
+        let expected_total = results.iter().fold(0.0, |acc, result| {
+            acc + e(glicko2.value, result.opponent_value(), result.opponent_deviation())
+        });
+        assert!(Relative::default().epsilon(0.0001).eq(&expected_total, &1.5));
+
+        let strong = [GameResult::win(opponent(1500.0)), GameResult::draw(opponent(1500.0))];
+        assert!(performance_rating(&strong).unwrap().value > 1500.0);
+
+        assert!(performance_rating::<f64>(&[]).is_none());
+        assert!(performance_rating(&[GameResult::win(opponent(1500.0))]).is_none());
+        assert!(performance_rating(&[GameResult::loss(opponent(1500.0))]).is_none());
+
+        // Games against opponents this uncertain carry no information,
+        // so the bracket is widened until it is no longer finite.
+        let unknowable = Glicko2Rating {
+            value: 0.0,
+            deviation: f64::MAX,
+            volatility: 0.06,
+        };
+        let results = [GameResult::win(unknowable), GameResult::draw(unknowable)];
+        assert!(performance_rating(&results).is_none());
+        let infinite = GlickoRating {
+            value: f64::INFINITY,
+            deviation: 50.0,
+        };
+        let results = [GameResult::win(infinite), GameResult::loss(opponent(1500.0))];
+        assert!(performance_rating(&results).is_none());
+    }
+
+    #[test]
+    fn test_try_new_rating() {
+        let example_player_rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let results = vec![
+            GameResult::win(GlickoRating {
+                value: 1400.0,
+                deviation: 30.0,
+            }),
+            GameResult::loss(GlickoRating {
+                value: 1550.0,
+                deviation: 100.0,
+            }),
+        ];
+
+        let new_rating = new_rating(example_player_rating, &results, 0.5);
+        let try_rating = try_new_rating(example_player_rating, &results, 0.5).unwrap();
+        assert!(Relative::default().epsilon(0.0001).eq(&try_rating.value, &new_rating.value));
+        assert!(Relative::default()
+            .epsilon(0.0001)
+            .eq(&try_rating.deviation, &new_rating.deviation));
+        assert!(Relative::default()
+            .epsilon(0.0001)
+            .eq(&try_rating.volatility, &new_rating.volatility));
+
+        let no_iterations = new_rating_inner(
+            example_player_rating,
+            &results,
+            RatingConfig::new(0.5),
+            Some(0),
+            &mut SolverStats::default(),
+        );
+        assert_eq!(no_iterations.unwrap_err(), Glicko2Error::NonConvergence { iterations: 0 });
+    }
+
+    #[test]
+    fn test_try_new_rating_invalid_input() {
+        let opponent: GlickoRating = GlickoRating::unrated();
+        let results = [GameResult::win(opponent)];
+        let nan_prior = Glicko2Rating {
+            value: f64::NAN,
+            ..Glicko2Rating::unrated()
+        };
+        assert_eq!(
+            try_new_rating(nan_prior, &results, 0.5),
+            Err(Glicko2Error::NonFinite { field: "value" })
+        );
+
+        let prior_rating = Glicko2Rating::unrated();
+        let nan_opponent = GlickoRating {
+            value: f64::NAN,
+            deviation: 200.0,
+        };
+        assert_eq!(
+            try_new_rating(prior_rating, &[GameResult::win(nan_opponent)], 0.5),
+            Err(Glicko2Error::NonFinite {
+                field: "opponent value"
+            })
+        );
+        let negative_deviation = GlickoRating {
+            value: 1500.0,
+            deviation: -200.0,
+        };
+        assert_eq!(
+            try_new_rating(prior_rating, &[GameResult::loss(negative_deviation)], 0.5),
+            Err(Glicko2Error::InvalidDeviation)
+        );
+        // The constructors never produce these scores, but deserialized results can have them.
+        for &score in &[f64::NAN, -0.5, 1.5] {
+            let invalid_score = GameResult {
+                score,
+                ..GameResult::draw(opponent)
+            };
+            assert_eq!(
+                try_new_rating(prior_rating, &[invalid_score], 0.5),
+                Err(Glicko2Error::InvalidScore)
+            );
+        }
+        assert_eq!(
+            try_new_rating(
+                prior_rating,
+                &[GameResult::weighted(opponent, 1.0, f64::INFINITY)],
+                0.5
+            ),
+            Err(Glicko2Error::NonFinite { field: "weight" })
+        );
+        assert!(try_new_rating(prior_rating, &results, 0.5).is_ok());
+    }
+
+    #[test]
+    fn test_apply_inactivity() {
+        let rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let inactive = apply_inactivity(rating);
+        let empty_period = new_rating(rating, &[], 0.5);
+        assert!(Relative::default().epsilon(0.0001).eq(&inactive.value, &rating.value));
+        assert!(Relative::default()
+            .epsilon(0.0001)
+            .eq(&inactive.deviation, &empty_period.deviation));
+        assert!(Relative::default().epsilon(0.0001).eq(&inactive.volatility, &rating.volatility));
+        assert!(inactive.deviation > rating.deviation);
+    }
+
+    #[test]
+    fn test_apply_inactivity_periods() {
+        let rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let mut looped = rating;
+        for _ in 0..12 {
+            looped = apply_inactivity(looped);
+        }
+        let direct = apply_inactivity_periods(rating, 12);
+        assert!(direct.approx_eq(&looped, 0.000001));
+        assert_eq!(apply_inactivity_periods(rating, 0), rating);
+        assert_eq!(apply_inactivity_periods(rating, 1), apply_inactivity(rating));
+    }
+
+    #[test]
+    fn test_deviation_after_idle() {
+        let rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        assert!(Relative::default().epsilon(0.000001).eq(
+            &deviation_after_idle(rating, 84.0, 7.0),
+            &apply_inactivity_periods(rating, 12).deviation
+        ));
+        assert_eq!(deviation_after_idle(rating, 0.0, 7.0), rating.deviation);
+
+        let half_period = deviation_after_idle(rating, 3.5, 7.0);
+        assert!(half_period > rating.deviation);
+        assert!(half_period < apply_inactivity(rating).deviation);
+
+        let degenerate = [(-7.0, 7.0), (f64::NAN, 7.0), (7.0, 0.0), (7.0, -7.0), (7.0, f64::NAN)];
+        for &(days, period_days) in &degenerate {
+            assert_eq!(deviation_after_idle(rating, days, period_days), rating.deviation);
+        }
+    }
+
+    #[test]
+    fn test_cap_deviation() {
+        let max_deviation = Glicko2Rating::<f64>::unrated().deviation;
+        let mut rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 340.0,
+        });
+        for _ in 0..100 {
+            rating = cap_deviation(apply_inactivity(rating), max_deviation);
+        }
+        assert!(Relative::default().epsilon(0.0001).eq(&rating.deviation, &2.0148));
+
+        let established = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 50.0,
+        });
+        assert_eq!(cap_deviation(established, max_deviation), established);
+    }
+
+    #[test]
+    fn test_win_probability() {
+        let stronger = Glicko2Rating::from(GlickoRating {
+            value: 1700.0,
+            deviation: 50.0,
+        });
+        let weaker = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 50.0,
+        });
+        let p = win_probability(stronger, weaker);
+        assert!(p > 0.5 && p < 1.0);
+        assert!(Relative::default()
+            .epsilon(0.0001)
+            .eq(&(p + win_probability(weaker, stronger)), &1.0));
+        assert!(Relative::default().epsilon(0.0001).eq(&win_probability(weaker, weaker), &0.5));
+
+        let uncertain_stronger = Glicko2Rating::from(GlickoRating {
+            value: 1700.0,
+            deviation: 350.0,
+        });
+        assert!(win_probability(uncertain_stronger, weaker) < p);
+
+        // Only the deviation of the opponent is used by new_rating, so the two agree only when
+        // the player is certain.
+        let certain_stronger = Glicko2Rating {
+            deviation: 0.0,
+            ..stronger
+        };
+        let new_rating_e = math::e(stronger.value, weaker.value, weaker.deviation);
+        assert_eq!(win_probability(certain_stronger, weaker), new_rating_e);
+        assert!(p < new_rating_e);
+    }
+
+    #[test]
+    fn test_rating_for_win_probability() {
+        let opponent = Glicko2Rating::from(GlickoRating {
+            value: 1600.0,
+            deviation: 80.0,
+        });
+        let value = rating_for_win_probability(opponent, 0.75);
+        assert!(value > 1600.0);
+        let player = GlickoRating {
+            value,
+            deviation: 0.0,
+        };
+        assert!(Relative::default()
+            .epsilon(0.000001)
+            .eq(&win_probability(player.into(), opponent), &0.75));
+        assert!(Relative::default()
+            .epsilon(0.000001)
+            .eq(&rating_for_win_probability(opponent, 0.5), &1600.0));
+        assert_eq!(rating_for_win_probability(opponent, 1.0), f64::INFINITY);
+        assert_eq!(rating_for_win_probability(opponent, 0.0), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_from_reference() {
+        let opponents = [
+            Glicko2Rating {
+                value: 0.5,
+                deviation: 0.3,
+                volatility: 0.06,
+            },
+            Glicko2Rating::unrated(),
+        ];
+        let results: Vec<GameResult> = opponents.iter().map(GameResult::win).collect();
+        assert_eq!(results[0].opponent_value(), 0.5);
+        assert_eq!(results[1].opponent_deviation(), opponents[1].deviation);
+
+        let opponents = [GlickoRating {
+            value: 1600.0,
+            deviation: 80.0,
+        }];
+        let results: Vec<GameResult> = opponents.iter().map(GameResult::loss).collect();
+        assert_eq!(
+            results[0].opponent_value(),
+            Glicko2Rating::from(opponents[0]).value
+        );
+    }
+
+    #[test]
+    fn test_pair_pool() {
+        let rating = |value| {
+            Glicko2Rating::from(GlickoRating {
+                value,
+                deviation: 50.0,
+            })
+        };
+        let ratings = [
+            rating(1500.0),
+            rating(2000.0),
+            rating(1520.0),
+            rating(1990.0),
+            rating(1200.0),
+        ];
+        assert_eq!(pair_pool(&ratings), vec![(1, 3), (0, 2)]);
+        assert_eq!(pair_pool(&ratings[..1]), vec![]);
+
+        // Equally good pairs are chosen by index.
+        let equal = [rating(1500.0); 4];
+        assert_eq!(pair_pool(&equal), vec![(0, 1), (2, 3)]);
+    }
+
+    #[test]
+    fn test_win_probability_point() {
+        let player = GlickoRating {
+            value: 1700.0,
+            deviation: 50.0,
+        };
+        let p = win_probability_point(player, 1500.0);
+        let established = GlickoRating {
+            value: 1500.0,
+            deviation: 0.0,
+        };
+        assert_eq!(p, win_probability(player.into(), established.into()));
+        let uncertain = GlickoRating {
+            value: 1500.0,
+            deviation: 50.0,
+        };
+        assert!(p > win_probability(player.into(), uncertain.into()));
+        assert!(Relative::default()
+            .epsilon(0.0001)
+            .eq(&win_probability_point(player, 1700.0), &0.5));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_round_trip() {
+        let rating = Glicko2Rating {
+            value: 0.25,
+            deviation: 1.5,
+            volatility: 0.07,
+        };
+        let json = to_json(&rating);
+        assert_eq!(json, r#"{"value":0.25,"deviation":1.5,"volatility":0.07}"#);
+        assert_eq!(from_json::<f64>(&json).unwrap(), rating);
+        assert!(from_json::<f64>("{\"value\":0.25}").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        extern crate serde_json;
+
+        let rating = Glicko2Rating {
+            value: 0.5,
+            deviation: 1.2,
+            volatility: 0.07,
+        };
+        let json = serde_json::to_string(&rating).unwrap();
+        let deserialized: Glicko2Rating = serde_json::from_str(&json).unwrap();
+        assert!(Relative::default().epsilon(0.0001).eq(&deserialized.value, &rating.value));
+        assert!(Relative::default().epsilon(0.0001).eq(&deserialized.deviation, &rating.deviation));
+        assert!(Relative::default()
+            .epsilon(0.0001)
+            .eq(&deserialized.volatility, &rating.volatility));
+
+        let result = GameResult::new(rating, 0.75);
+        let json = serde_json::to_string(&result).unwrap();
+        let deserialized: GameResult = serde_json::from_str(&json).unwrap();
+        assert!(Relative::default().epsilon(0.0001).eq(&deserialized.weight, &1.0));
+        let unweighted_json = r#"{"opponent_rating_value":0.5,"opponent_rating_deviation":1.2,"score":0.75}"#;
+        let deserialized: GameResult = serde_json::from_str(unweighted_json).unwrap();
+        assert!(Relative::default().epsilon(0.0001).eq(&deserialized.opponent_rating_value, &0.5));
+        assert!(Relative::default()
+            .epsilon(0.0001)
+            .eq(&deserialized.opponent_rating_deviation, &1.2));
+        assert!(Relative::default().epsilon(0.0001).eq(&deserialized.score, &0.75));
+    }
+
+    #[test]
+    fn test_expected_score() {
+        let player = GlickoRating {
+            value: 1700.0,
+            deviation: 50.0,
+        };
+        let opponent = GlickoRating {
+            value: 1500.0,
+            deviation: 80.0,
+        };
+        let p = player.expected_score(&opponent);
+        assert!(p > 0.5);
+        assert!(Relative::default().epsilon(0.0001).eq(
+            &p,
+            &win_probability(Glicko2Rating::from(player), Glicko2Rating::from(opponent))
+        ));
+    }
+
+    #[test]
+    fn test_match_quality() {
+        let average = Glicko2Rating::unrated();
+        let strong = Glicko2Rating::from(GlickoRating {
+            value: 1800.0,
+            deviation: 50.0,
+        });
+        let very_strong = Glicko2Rating::from(GlickoRating {
+            value: 2400.0,
+            deviation: 50.0,
+        });
+        assert!(Relative::default().epsilon(0.0001).eq(&match_quality(average, average), &1.0));
+        assert!(Relative::default()
+            .epsilon(0.0001)
+            .eq(&match_quality(average, strong), &match_quality(strong, average)));
+        assert!(match_quality(average, strong) > match_quality(average, very_strong));
+        assert!(match_quality(average, very_strong) > 0.0);
+    }
+
+    #[test]
+    fn test_degenerate_sys_constant() {
+        let rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let results = [GameResult::draw(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        })];
+
+        // A system constant this small vanishes next to the log volatility,
+        // so the search for the range containing the new volatility never makes progress
+        assert_eq!(
+            try_new_rating(rating, &results, 1e-20).unwrap_err(),
+            Glicko2Error::NonConvergence {
+                iterations: MAX_ITERATIONS
+            }
+        );
+        let fallback = new_rating(rating, &results, 1e-20);
+        assert!(fallback.value.is_finite());
+        assert!(fallback.deviation.is_finite());
+        assert!(Relative::default().epsilon(0.0001).eq(&fallback.volatility, &rating.volatility));
+    }
+
+    #[test]
+    fn test_sys_constant_bounds() {
+        let prior = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let results = [
+            GameResult::win(GlickoRating {
+                value: 1400.0,
+                deviation: 30.0,
+            }),
+            GameResult::loss(GlickoRating {
+                value: 1550.0,
+                deviation: 100.0,
+            }),
+            GameResult::loss(GlickoRating {
+                value: 1700.0,
+                deviation: 300.0,
+            }),
+        ];
+
+        // The recommended bounds only make a small difference to the paper's example.
+        for &(sys_constant, volatility) in &[(0.3, 0.059999), (1.2, 0.059977)] {
+            let rating = try_new_rating(prior, &results, sys_constant).unwrap();
+            assert!(Relative::default().epsilon(0.000001).eq(&rating.volatility, &volatility));
+            assert!(Relative::default().epsilon(0.0001).eq(&rating.value, &-0.2069));
+            assert!(Relative::default().epsilon(0.0001).eq(&rating.deviation, &0.8722));
+        }
+        assert!(try_new_rating(prior, &results, 10.0).is_ok());
+
+        for &sys_constant in &[0.0, -0.5, f64::NAN, 10.5, 1e6, f64::INFINITY] {
+            assert_eq!(
+                try_new_rating(prior, &results, sys_constant),
+                Err(Glicko2Error::InvalidSysConstant)
+            );
+        }
+        // An absurdly large system constant collapses the volatility.
+        assert!(new_rating(prior, &results, 1e6).volatility < 0.0001);
+    }
+
+    #[test]
+    fn test_team_rating() {
+        let member = Glicko2Rating::from(GlickoRating {
+            value: 1600.0,
+            deviation: 80.0,
+        });
+        let team = team_rating(&[member, member]).unwrap();
+        assert!(Relative::default().epsilon(0.0001).eq(&team.value, &member.value));
+        assert!(Relative::default().epsilon(0.0001).eq(&team.deviation, &member.deviation));
+        assert!(Relative::default().epsilon(0.0001).eq(&team.volatility, &member.volatility));
+
+        let team = team_rating(&[
+            Glicko2Rating {
+                value: 1.0,
+                deviation: 1.0,
+                volatility: 0.06,
+            },
+            Glicko2Rating {
+                value: 0.0,
+                deviation: 0.0,
+                volatility: 0.06,
+            },
+        ])
+        .unwrap();
+        assert!(Relative::default().epsilon(0.0001).eq(&team.value, &0.5));
+        assert!(Relative::default().epsilon(0.0001).eq(&team.deviation, &0.5f64.sqrt()));
+        assert!(Relative::default().epsilon(0.0001).eq(&team.volatility, &0.06));
+
+        assert!(team_rating::<f64>(&[]).is_none());
+    }
+
+    #[test]
+    fn test_game_result_new() {
+        let opponent = GlickoRating::unrated();
+        assert!(Relative::default()
+            .epsilon(0.0001)
+            .eq(&GameResult::new(opponent, 0.75).score, &0.75));
+        assert!(Relative::default()
+            .epsilon(0.0001)
+            .eq(&GameResult::new(opponent, 1.5).score, &1.0));
+        assert!(Relative::default()
+            .epsilon(0.0001)
+            .eq(&GameResult::new(opponent, -0.5).score, &0.0));
+        assert_eq!(GameResult::new(opponent, f64::NAN).score(), 0.5);
+        assert_eq!(GameResult::weighted(opponent, f64::NAN, 2.0).score(), 0.5);
+    }
+
+    #[test]
+    fn test_weighted_results() {
+        let rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let opponent = GlickoRating {
+            value: 1400.0,
+            deviation: 30.0,
+        };
+
+        let full_weight = [GameResult::weighted(opponent, 1.0, 1.0)];
+        assert_eq!(
+            new_rating(rating, &full_weight, 0.5),
+            new_rating(rating, &[GameResult::win(opponent)], 0.5)
+        );
+
+        let doubled = new_rating(rating, &[GameResult::weighted(opponent, 1.0, 2.0)], 0.5);
+        let twice = new_rating(
+            rating,
+            &[GameResult::win(opponent), GameResult::win(opponent)],
+            0.5,
+        );
+        assert!(Relative::default().epsilon(0.0001).eq(&doubled.value, &twice.value));
+        assert!(Relative::default().epsilon(0.0001).eq(&doubled.deviation, &twice.deviation));
+
+        let decayed = new_rating(rating, &[GameResult::weighted(opponent, 1.0, 0.5)], 0.5);
+        assert!(decayed.value > rating.value);
+        assert!(decayed.value < new_rating(rating, &full_weight, 0.5).value);
+        assert!(Relative::default()
+            .epsilon(0.0001)
+            .eq(&GameResult::weighted(opponent, 1.0, -1.0).weight(), &0.0));
+    }
+
+    #[test]
+    fn test_game_result_accessors() {
+        let opponent = Glicko2Rating {
+            value: 0.5,
+            deviation: 1.2,
+            volatility: 0.06,
+        };
+        let result = GameResult::new(opponent, 0.75);
+        assert!(Relative::default().epsilon(0.0001).eq(&result.opponent_value(), &0.5));
+        assert!(Relative::default().epsilon(0.0001).eq(&result.opponent_deviation(), &1.2));
+        assert!(Relative::default().epsilon(0.0001).eq(&result.score(), &0.75));
+    }
+
+    #[test]
+    fn test_from_outcomes() {
+        let opponent: GlickoRating = GlickoRating::unrated();
+        let results = GameResult::from_outcomes(vec![
+            (Outcome::Win, opponent),
+            (Outcome::Loss, opponent),
+            (Outcome::Draw, opponent),
+        ]);
+        assert_eq!(results.len(), 3);
+        assert!(Relative::default().epsilon(0.0001).eq(&results[0].score, &1.0));
+        assert!(Relative::default().epsilon(0.0001).eq(&results[1].score, &0.0));
+        assert!(Relative::default().epsilon(0.0001).eq(&results[2].score, &0.5));
+    }
+
+    #[test]
+    fn test_unrated_with_volatility() {
+        let default_unrated = Glicko2Rating::unrated();
+        let unrated = Glicko2Rating::unrated_with_volatility(0.09);
+        assert!(Relative::default().epsilon(0.0001).eq(&unrated.value, &default_unrated.value));
+        assert!(Relative::default()
+            .epsilon(0.0001)
+            .eq(&unrated.deviation, &default_unrated.deviation));
+        assert!(Relative::default().epsilon(0.0001).eq(&unrated.volatility, &0.09));
+    }
+
+    #[test]
+    fn test_confidence_interval() {
+        let rating = GlickoRating {
+            value: 1500.0,
+            deviation: 100.0,
+        };
+        let (lower, upper) = rating.confidence_interval(1.96);
+        assert!(Relative::default().epsilon(0.0001).eq(&lower, &1304.0));
+        assert!(Relative::default().epsilon(0.0001).eq(&upper, &1696.0));
+    }
+
+    #[test]
+    fn test_hashable_rating() {
+        use std::collections::HashMap;
+
+        let rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let mut cache = HashMap::new();
+        cache.insert(HashableRating(rating), new_rating(rating, &[], 0.5));
+        assert_eq!(
+            cache.get(&HashableRating(rating)),
+            Some(&new_rating(rating, &[], 0.5))
+        );
+
+        let nan_rating = Glicko2Rating {
+            value: f64::NAN,
+            ..rating
+        };
+        assert_eq!(HashableRating(nan_rating), HashableRating(nan_rating));
+        let zero_rating = Glicko2Rating { value: 0.0, ..rating };
+        let negative_zero_rating = Glicko2Rating { value: -0.0, ..rating };
+        assert_ne!(HashableRating(zero_rating), HashableRating(negative_zero_rating));
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert!(GlickoRating::try_new(1500.0, 200.0).is_ok());
+        assert_eq!(
+            GlickoRating::try_new(f64::NAN, 200.0).unwrap_err(),
+            Glicko2Error::NonFinite { field: "value" }
+        );
+        assert_eq!(
+            GlickoRating::try_new(1500.0, -1.0).unwrap_err(),
+            Glicko2Error::InvalidDeviation
+        );
+
+        assert!(Glicko2Rating::try_new(0.0, 1.0, 0.06).is_ok());
+        assert_eq!(
+            Glicko2Rating::try_new(0.0, f64::INFINITY, 0.06).unwrap_err(),
+            Glicko2Error::NonFinite { field: "deviation" }
+        );
+        assert_eq!(
+            Glicko2Rating::try_new(0.0, 1.0, f64::NAN).unwrap_err(),
+            Glicko2Error::NonFinite { field: "volatility" }
+        );
+        assert_eq!(
+            Glicko2Rating::try_new(0.0, 1.0, 0.0).unwrap_err(),
+            Glicko2Error::InvalidVolatility
+        );
+    }
+
+    #[test]
+    fn test_is_provisional() {
+        assert!(GlickoRating::unrated().is_provisional(PROVISIONAL_DEVIATION));
+        let established = GlickoRating {
+            value: 1500.0,
+            deviation: 60.0,
+        };
+        assert!(!established.is_provisional(PROVISIONAL_DEVIATION));
+    }
+
+    #[test]
+    fn test_glicko_interval() {
+        let rating = GlickoRating {
+            value: 1500.0,
+            deviation: 100.0,
+        };
+        let (lower, upper) = Glicko2Rating::from(rating).glicko_interval(2.0);
+        assert!(Relative::default().epsilon(0.0001).eq(&lower, &1300.0));
+        assert!(Relative::default().epsilon(0.0001).eq(&upper, &1700.0));
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let rating = GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        };
+        let round_tripped = GlickoRating::from(Glicko2Rating::from(rating));
+        assert!(rating.approx_eq(&round_tripped, 1e-9));
+        assert!(!rating.approx_eq(&GlickoRating::unrated(), 1.0));
+
+        let glicko2_rating = Glicko2Rating::from(rating);
+        let nudged = Glicko2Rating {
+            volatility: glicko2_rating.volatility + 0.001,
+            ..glicko2_rating
+        };
+        assert!(glicko2_rating.approx_eq(&nudged, 0.01));
+        assert!(!glicko2_rating.approx_eq(&nudged, 0.0001));
+        let nan = Glicko2Rating {
+            value: f64::NAN,
+            ..glicko2_rating
+        };
+        assert!(!nan.approx_eq(&nan, 1.0));
+    }
+
+    #[test]
+    fn test_cmp_by_value() {
+        let mut ratings = [
+            GlickoRating {
+                value: 1600.0,
+                deviation: 50.0,
+            },
+            GlickoRating {
+                value: f64::NAN,
+                deviation: 50.0,
+            },
+            GlickoRating {
+                value: 1400.0,
+                deviation: 200.0,
+            },
+        ];
+        ratings.sort_by(GlickoRating::cmp_by_value);
+        assert_eq!(ratings[0].value, 1400.0);
+        assert_eq!(ratings[1].value, 1600.0);
+        assert!(ratings[2].value.is_nan());
+
+        let rating = Glicko2Rating::unrated();
+        assert_eq!(rating, rating);
+        assert_eq!(rating.cmp_by_value(&rating), Ordering::Equal);
+        assert_ne!(rating, Glicko2Rating::unrated_with_volatility(0.09));
+    }
+
+    #[test]
+    fn test_display() {
+        let rating = GlickoRating {
+            value: 1500.0,
+            deviation: 350.0,
+        };
+        assert_eq!(rating.to_string(), "1500 ± 350");
+        assert_eq!(format!("{:.1}", rating), "1500.0 ± 350.0");
+
+        let glicko2_rating = Glicko2Rating {
+            value: 0.0,
+            deviation: 1.0,
+            volatility: 0.06,
+        };
+        assert_eq!(
+            format!("{:.2}", glicko2_rating),
+            "0.00 ± 1.00, volatility 0.06 (1500.00 ± 173.72)"
+        );
+    }
+
+    #[test]
+    fn test_percentile() {
+        let population: Vec<GlickoRating> = [1200.0, 1400.0, 1500.0, 1500.0, 1800.0]
+            .iter()
+            .map(|&value| GlickoRating {
+                value,
+                deviation: 100.0,
+            })
+            .collect();
+        assert!(Relative::default()
+            .epsilon(0.0001)
+            .eq(&percentile(&population[4], &population), &0.9));
+        assert!(Relative::default()
+            .epsilon(0.0001)
+            .eq(&percentile(&population[2], &population), &0.6));
+        assert!(Relative::default()
+            .epsilon(0.0001)
+            .eq(&percentile(&population[0], &population), &0.1));
+        assert!(percentile(&population[0], &[]).is_nan());
+    }
+
+    #[test]
+    fn test_from_str() {
+        let rating: GlickoRating = " 1500 / 2e2 ".parse().unwrap();
+        assert!(Relative::default().epsilon(0.0001).eq(&rating.value, &1500.0));
+        assert!(Relative::default().epsilon(0.0001).eq(&rating.deviation, &200.0));
+
+        let glicko2_rating: Glicko2Rating = "0.5/1.2/0.06".parse().unwrap();
+        assert!(Relative::default().epsilon(0.0001).eq(&glicko2_rating.value, &0.5));
+        assert!(Relative::default().epsilon(0.0001).eq(&glicko2_rating.deviation, &1.2));
+        assert!(Relative::default().epsilon(0.0001).eq(&glicko2_rating.volatility, &0.06));
+
+        assert_eq!(
+            "1500/200".parse::<Glicko2Rating>().unwrap_err(),
+            ParseRatingError::WrongFieldCount {
+                expected: 3,
+                found: 2
+            }
+        );
+        assert_eq!(
+            "1500/abc".parse::<GlickoRating>().unwrap_err(),
+            ParseRatingError::InvalidNumber { field: "deviation" }
+        );
+    }
+
+    #[test]
+    fn test_tuple_conversions() {
+        assert_eq!(
+            GlickoRating::from((1500.0, 200.0)),
+            GlickoRating {
+                value: 1500.0,
+                deviation: 200.0,
+            }
+        );
+        assert_eq!(
+            Glicko2Rating::from((0.5, 1.2, 0.06)),
+            Glicko2Rating {
+                value: 0.5,
+                deviation: 1.2,
+                volatility: 0.06,
+            }
+        );
+    }
+
+    #[test]
+    fn test_scaled_conversions() {
+        let params = ScaleParams {
+            base: 0.0,
+            scale: 100.0,
+        };
+        let rating = GlickoRating {
+            value: 50.0,
+            deviation: 200.0,
+        };
+
+        let glicko2_rating = to_glicko2_scaled(rating, params);
+        assert!(Relative::default().epsilon(0.0001).eq(&glicko2_rating.value, &0.5));
+        assert!(Relative::default().epsilon(0.0001).eq(&glicko2_rating.deviation, &2.0));
+        assert!(Relative::default().epsilon(0.0001).eq(&glicko2_rating.volatility, &0.06));
+
+        let glicko_rating = to_glicko_scaled(glicko2_rating, params);
+        assert!(Relative::default().epsilon(0.0001).eq(&glicko_rating.value, &50.0));
+        assert!(Relative::default().epsilon(0.0001).eq(&glicko_rating.deviation, &200.0));
+
+        let standard = to_glicko_scaled(glicko2_rating, ScaleParams::standard());
+        assert_eq!(standard, GlickoRating::from(glicko2_rating));
     }
 
     #[test]
@@ -379,4 +5269,33 @@ mod tests {
         assert!(Relative::default().epsilon(0.0001).eq(&glicko_rating.value, &1500.0));
         assert!(Relative::default().epsilon(0.0001).eq(&glicko_rating.deviation, &200.0));
     }
+
+    #[test]
+    fn test_conversion_round_trips() {
+        let values = (0..=400).map(|i| i as f64 * 10.0 + 0.37);
+        for value in values.chain([0.0, 4000.0]) {
+            let deviations = (1..=700).step_by(3).map(|i| i as f64);
+            for deviation in deviations.chain([700.0, 123.456]) {
+                let rating = GlickoRating { value, deviation };
+                let round_trip = GlickoRating::from(Glicko2Rating::from(rating));
+                assert!(round_trip.approx_eq(&rating, 0.000000001), "{:?}", rating);
+
+                let repeated = (0..10).fold(rating, |rating, _| {
+                    GlickoRating::from(Glicko2Rating::from(rating))
+                });
+                assert!(repeated.approx_eq(&rating, 0.000000001), "{:?}", rating);
+
+                let rating = GlickoRating {
+                    value: value as f32,
+                    deviation: deviation as f32,
+                };
+                let round_trip = GlickoRating::from(Glicko2Rating::from(rating));
+                assert!(round_trip.approx_eq(&rating, 0.001), "{:?}", rating);
+
+                let glicko2_rating = Glicko2Rating::from(GlickoRating { value, deviation });
+                let round_trip = Glicko2Rating::from(GlickoRating::from(glicko2_rating));
+                assert!(round_trip.approx_eq(&glicko2_rating, 0.000000000001));
+            }
+        }
+    }
 }
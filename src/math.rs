@@ -0,0 +1,88 @@
+//! The mathematical building blocks of the glicko2 rating system.
+//!
+//! These are best read alongside the [glicko2 paper](http://www.glicko.net/glicko/glicko2.pdf),
+//! and are exposed for validating intermediate steps of the calculations or building custom variants.
+//! Note well that all of these functions operate on the Glicko2 (not Glicko) scale.
+
+use cast;
+use num_traits::Float;
+
+/// The `g(φ)` function from the glicko2 paper, which weights the impact of a game
+/// by the rating deviation `rating_deviation` of the opponent.
+///
+/// The more uncertain the rating of the opponent, the smaller the result and thus the impact of the game.
+pub fn g<F: Float>(rating_deviation: F) -> F {
+    let pi: F = cast(core::f64::consts::PI);
+    let denom = F::one() + ((cast::<F>(3.0) * rating_deviation * rating_deviation) / (pi * pi));
+    denom.sqrt().recip()
+}
+
+/// The `E(μ, μj, φj)` function from the glicko2 paper, which is the expected score of a game
+/// between a player or team with rating value `rating` and an opponent with rating value `other_rating`
+/// and rating deviation `other_rating_deviation`.
+pub fn e<F: Float>(rating: F, other_rating: F, other_rating_deviation: F) -> F {
+    let base = -g(other_rating_deviation) * (rating - other_rating);
+    (F::one() + base.exp()).recip()
+}
+
+/// The `f(x)` function from step 5 of the glicko2 paper, whose root is the logarithm of the square
+/// of the new volatility, `ln(σ'²)`.
+///
+/// `delta` is the estimated improvement in rating `Δ`, `deviation` the prior rating deviation `φ`,
+/// `v` the estimated variance of the rating based only on the results, `volatility` the prior
+/// volatility `σ` and `sys_constant` the system constant `τ`. `Δ` and `v` can be calculated with
+/// [`compute_delta`](../fn.compute_delta.html) and [`compute_v`](../fn.compute_v.html).
+///
+/// This is exactly the function that [`new_rating`](../fn.new_rating.html) finds the root of,
+/// so it can be used to validate the solver or to try alternative root finders.
+/// The function is decreasing in `x`, and is zero at `x = ln(σ'²)`.
+pub fn volatility_objective<F: Float>(
+    x: F,
+    delta: F,
+    deviation: F,
+    v: F,
+    volatility: F,
+    sys_constant: F,
+) -> F {
+    let fraction_one = {
+        let numer = x.exp() * ((delta * delta) - (deviation * deviation) - v - x.exp());
+        let denom = cast::<F>(2.0) * (deviation * deviation + v + x.exp())
+            * (deviation * deviation + v + x.exp());
+        numer / denom
+    };
+    let fraction_two = {
+        let numer = x - (volatility * volatility).ln();
+        let denom = sys_constant * sys_constant;
+        numer / denom
+    };
+    fraction_one - fraction_two
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate approx;
+    use self::approx::*;
+    use super::*;
+
+    #[test]
+    fn test_paper_intermediate_values() {
+        // The intermediate values from step 3 of the example in the glicko2 paper
+        assert!(Relative::default().epsilon(0.0001).eq(&g(0.1727), &0.9955));
+        assert!(Relative::default().epsilon(0.0001).eq(&g(0.5756), &0.9531));
+        assert!(Relative::default().epsilon(0.0001).eq(&g(1.7269), &0.7242));
+        assert!(Relative::default().epsilon(0.001).eq(&e(0.0, -0.5756, 0.1727), &0.639));
+        assert!(Relative::default().epsilon(0.001).eq(&e(0.0, 0.2878, 0.5756), &0.432));
+        assert!(Relative::default().epsilon(0.001).eq(&e(0.0, 1.1513, 1.7269), &0.303));
+    }
+
+    #[test]
+    fn test_volatility_objective() {
+        // The example in the glicko2 paper has Δ = -0.4834, φ = 1.1513, v = 1.7785, σ = 0.06
+        // and τ = 0.5, and finds a new volatility of 0.05999 (more precisely, 0.059996).
+        let objective = |x: f64| volatility_objective(x, -0.4834, 1.1513, 1.7785, 0.06, 0.5);
+        let root = (0.059996_f64 * 0.059996).ln();
+        assert!(Relative::default().epsilon(0.0001).eq(&objective(root), &0.0));
+        assert!(objective(root - 0.1) > 0.0);
+        assert!(objective(root + 0.1) < 0.0);
+    }
+}
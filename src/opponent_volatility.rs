@@ -0,0 +1,69 @@
+//! An experimental variant of glicko2 that takes the volatility of opponents into account.
+//!
+//! Standard glicko2 ignores the volatility of opponents. This module provides a simple heuristic
+//! specific to this crate, for researchers comparing systems on identical data: the deviation
+//! of each opponent is inflated by their volatility, as if their rating had gone one further
+//! rating period without being updated, before the `g(φ)` weighting is applied. More volatile
+//! opponents are thus treated as less reliable, and games against them count for less.
+//!
+//! This is not Glickman's Glicko-Boost, or any other published system, and it has not been
+//! validated. Its predictive accuracy should be tested before being relied upon.
+
+use alloc::vec::Vec;
+use num_traits::Float;
+use {new_rating, GameResult, Glicko2Rating};
+
+/// Calculates a new rating from an existing rating and a series of results, where each result
+/// is the full rating of the opponent paired with the score of the game (1.0 for a win,
+/// 0.0 for a loss, 0.5 for a draw).
+///
+/// This is identical to [`new_rating`](../fn.new_rating.html), except that the deviation of each
+/// opponent is replaced by `sqrt(φ² + σ²)`, where `φ` and `σ` are the deviation and volatility
+/// of the opponent.
+pub fn new_rating_with_opponent_volatility<F: Float>(
+    prior_rating: Glicko2Rating<F>,
+    results: &[(Glicko2Rating<F>, F)],
+    sys_constant: F,
+) -> Glicko2Rating<F> {
+    let results: Vec<GameResult<F>> = results
+        .iter()
+        .map(|&(opponent, score)| {
+            let inflated_opponent = Glicko2Rating {
+                deviation: ((opponent.deviation * opponent.deviation)
+                    + (opponent.volatility * opponent.volatility))
+                    .sqrt(),
+                ..opponent
+            };
+            GameResult::new(inflated_opponent, score)
+        })
+        .collect();
+    new_rating(prior_rating, &results, sys_constant)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use GlickoRating;
+
+    #[test]
+    fn test_volatile_opponents_count_less() {
+        let rating = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 200.0,
+        });
+        let steady = Glicko2Rating::from(GlickoRating {
+            value: 1500.0,
+            deviation: 50.0,
+        });
+        let volatile = Glicko2Rating {
+            volatility: 0.5,
+            ..steady
+        };
+
+        let standard = new_rating(rating, &[GameResult::win(steady)], 0.5);
+        let against_steady = new_rating_with_opponent_volatility(rating, &[(steady, 1.0)], 0.5);
+        let against_volatile = new_rating_with_opponent_volatility(rating, &[(volatile, 1.0)], 0.5);
+        assert!(against_steady.approx_eq(&standard, 0.01));
+        assert!(against_volatile.value < against_steady.value);
+    }
+}
@@ -0,0 +1,108 @@
+//! Mapping ratings to named tiers, such as Bronze, Silver and Gold.
+//!
+//! ```
+//! # use glicko2::tiers::Tiers;
+//! # use glicko2::GlickoRating;
+//! let tiers = Tiers::new("Bronze")
+//!     .tier(1400.0, "Silver")
+//!     .tier(1700.0, "Gold")
+//!     .conservative(2.0);
+//! let rating = GlickoRating {
+//!     value: 1750.0,
+//!     deviation: 100.0,
+//! };
+//! // The conservative rating is 1750 - 2 * 100 = 1550.
+//! assert_eq!(tiers.tier_of(&rating), "Silver");
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use num_traits::Float;
+use GlickoRating;
+
+/// A set of named tiers, each starting at a rating value on the Glicko scale.
+#[derive(Clone, Debug)]
+pub struct Tiers<F = f64> {
+    lowest: String,
+    // Sorted by ascending minimum value.
+    tiers: Vec<(F, String)>,
+    conservative_k: Option<F>,
+}
+
+impl<F: Float> Tiers<F> {
+    /// Constructs a set of tiers containing only the lowest tier, named `lowest`,
+    /// which every rating below the minimum of every other tier falls into.
+    pub fn new<S: Into<String>>(lowest: S) -> Tiers<F> {
+        Tiers {
+            lowest: lowest.into(),
+            tiers: Vec::new(),
+            conservative_k: None,
+        }
+    }
+
+    /// Adds a tier named `name` for ratings of at least `min_value`, on the Glicko scale.
+    ///
+    /// Tiers can be added in any order.
+    pub fn tier<S: Into<String>>(mut self, min_value: F, name: S) -> Tiers<F> {
+        let index = self.tiers.partition_point(|&(other_min, _)| other_min <= min_value);
+        self.tiers.insert(index, (min_value, name.into()));
+        self
+    }
+
+    /// Places ratings into tiers by their value minus `k` times their deviation, as calculated by
+    /// [`GlickoRating::conservative`](../struct.GlickoRating.html#method.conservative),
+    /// rather than by their value alone.
+    ///
+    /// This avoids promoting players or teams whose ratings are still uncertain.
+    /// A `k` of 2 places ratings by the lower bound of their approximate 95% confidence interval.
+    pub fn conservative(mut self, k: F) -> Tiers<F> {
+        self.conservative_k = Some(k);
+        self
+    }
+
+    /// Returns the index of the tier that `rating` falls into,
+    /// where the lowest tier is 0 and each higher tier is one more than the last.
+    pub fn tier_index(&self, rating: &GlickoRating<F>) -> usize {
+        let value = match self.conservative_k {
+            Some(k) => rating.conservative(k),
+            None => rating.value,
+        };
+        self.tiers
+            .iter()
+            .take_while(|&&(min_value, _)| value >= min_value)
+            .count()
+    }
+
+    /// Returns the name of the tier that `rating` falls into.
+    pub fn tier_of(&self, rating: &GlickoRating<F>) -> &str {
+        match self.tier_index(rating) {
+            0 => &self.lowest,
+            index => &self.tiers[index - 1].1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tier_of() {
+        let tiers = Tiers::new("Bronze")
+            .tier(1700.0, "Gold")
+            .tier(1400.0, "Silver");
+        let rating = |value| GlickoRating {
+            value,
+            deviation: 100.0,
+        };
+        assert_eq!(tiers.tier_of(&rating(1000.0)), "Bronze");
+        assert_eq!(tiers.tier_of(&rating(1400.0)), "Silver");
+        assert_eq!(tiers.tier_of(&rating(1699.0)), "Silver");
+        assert_eq!(tiers.tier_index(&rating(2500.0)), 2);
+        assert_eq!(tiers.tier_of(&rating(2500.0)), "Gold");
+
+        let conservative = tiers.conservative(2.0);
+        assert_eq!(conservative.tier_of(&rating(1750.0)), "Silver");
+        assert_eq!(conservative.tier_of(&rating(1900.0)), "Gold");
+    }
+}